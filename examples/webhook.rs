@@ -0,0 +1,31 @@
+//! Posts today's board and its best solutions as JSON to the URL in `WEBHOOK_URL`.
+//!
+//! ```sh
+//! WEBHOOK_URL=https://example.com/hook cargo run --release --example webhook
+//! ```
+
+use letter_boxed_bot::solve_today;
+use reqwest::blocking;
+use reqwest::header::CONTENT_TYPE;
+use std::env;
+use std::error::Error;
+
+/// How many solutions to include in the payload.
+const TOP_SOLUTIONS: usize = 5;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let url = env::var("WEBHOOK_URL").map_err(|_| "WEBHOOK_URL must be set")?;
+
+    let mut report = solve_today()?;
+    report.solutions.truncate(TOP_SOLUTIONS);
+
+    let response = blocking::Client::new()
+        .post(&url)
+        .header(CONTENT_TYPE, "application/json")
+        .body(serde_json::to_string(&report)?)
+        .send()?
+        .error_for_status()?;
+
+    println!("Posted {} solutions to {} ({})", report.solutions.len(), url, response.status());
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//! Solving many boards at once from CSV.
+
+use crate::{Game, Sides, Solver};
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// Reads rows of `date,top,right,bottom,left` and writes `date,best_solution,word_count,total_letters` for each,
+/// with the words of the best solution separated by spaces. A header row starting with `date` is skipped, as are
+/// blank lines. A row that can't be solved gets its error in place of the solution, with the counts left empty,
+/// so one bad row doesn't stop the batch. Only failing to read or write stops it.
+pub fn solve_csv(input: impl Read, mut output: impl Write) -> io::Result<()> {
+    writeln!(output, "date,best_solution,word_count,total_letters")?;
+
+    for line in BufReader::new(input).lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with("date,") {
+            continue;
+        }
+
+        let columns = line.split(',').map(str::trim).collect::<Vec<&str>>();
+        let date = columns[0];
+        match solve_row(&columns) {
+            Ok(solution) => writeln!(output, "{},{},{},{}", field(date), solution.join(" "), solution.len(), solution.join("").len())?,
+            Err(error) => writeln!(output, "{},{},,", field(date), field(&format!("error: {}", error)))?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Solves the board in the given columns, giving the best solution.
+fn solve_row(columns: &[&str]) -> Result<Vec<String>, String> {
+    let [_, top, right, bottom, left] = columns else {
+        return Err(format!("expected 5 columns, got {}", columns.len()));
+    };
+
+    let sides = Sides::try_from([top, right, bottom, left].map(|side| side.to_uppercase())).map_err(|e| e.to_string())?;
+    let solutions = Solver::try_solve(&Game::new(sides)).map_err(|e| e.to_string())?;
+    Ok(solutions.into_iter().next().unwrap())
+}
+
+/// Quotes the field if it contains a comma or quote.
+fn field(value: &str) -> String {
+    if value.contains(',') || value.contains('"') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_csv() {
+        let input = "date,top,right,bottom,left\n\
+            2024-04-01,dki,jta,clv,ero\n\
+            \n\
+            2024-04-02,DKI,JTA,CLV,EROD\n\
+            2024-04-03,DKI,JTA,CLV,ERD\n\
+            2024-04-04,DKI,JTA\n";
+        let mut output = Vec::new();
+        solve_csv(input.as_bytes(), &mut output).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().collect::<Vec<&str>>(), vec![
+            "date,best_solution,word_count,total_letters",
+            "2024-04-01,OKRA ADJECTIVAL,2,14",
+            "2024-04-02,\"error: expected 3 letters on the left side, got 4\",,",
+            "2024-04-03,error: 'D' appears more than once,,",
+            "2024-04-04,\"error: expected 5 columns, got 3\",,",
+        ]);
+    }
+}
@@ -1,8 +1,16 @@
+use crate::SidesError;
+#[cfg(feature = "fetch")]
 use reqwest::blocking;
 use serde::{Deserialize, Serialize};
 
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
 #[allow(non_snake_case)]
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GameData {
     pub date: String,
     pub dictionary: Vec<String>,
@@ -19,26 +27,452 @@ pub struct GameData {
     pub yesterdaysSolution: Vec<String>,
 }
 
+/// The format NYT uses for `date` and `printDate`, like `2024-03-27`.
+#[cfg(feature = "chrono")]
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+#[cfg(feature = "chrono")]
+impl GameData {
+    /// Parses the date of the game. The raw string is left in `date`.
+    pub fn parsed_date(&self) -> Result<chrono::NaiveDate, chrono::ParseError> {
+        chrono::NaiveDate::parse_from_str(&self.date, DATE_FORMAT)
+    }
+
+    /// Parses the date the game was printed. The raw string is left in `printDate`.
+    pub fn parsed_print_date(&self) -> Result<chrono::NaiveDate, chrono::ParseError> {
+        chrono::NaiveDate::parse_from_str(&self.printDate, DATE_FORMAT)
+    }
+}
+
+#[cfg(feature = "fetch")]
+const GAME_URL: &str = "https://www.nytimes.com/puzzles/letter-boxed";
+
+#[cfg(feature = "fetch")]
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// The reason the game data couldn't be fetched.
+#[derive(Debug)]
+pub enum DataError {
+    /// The request to the game website failed.
+    #[cfg(feature = "fetch")]
+    Request(reqwest::Error),
+    /// No script on the page sets `window.gameData`.
+    MissingScript,
+    /// The game data couldn't be parsed.
+    Parse(serde_json::Error),
+    /// The game data doesn't describe a valid board.
+    InvalidBoard(SidesError),
+    /// A game data file couldn't be read or written.
+    Io(io::Error),
+    /// The blocking task fetching the game data panicked or was cancelled.
+    #[cfg(feature = "tokio")]
+    Join(tokio::task::JoinError),
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            #[cfg(feature = "fetch")]
+            Self::Request(error) => write!(f, "request failed: {}", error),
+            Self::MissingScript => write!(f, "no game data found on the page"),
+            Self::Parse(error) => write!(f, "couldn't parse game data: {}", error),
+            Self::InvalidBoard(error) => write!(f, "invalid board: {}", error),
+            Self::Io(error) => write!(f, "couldn't read or write game data file: {}", error),
+            #[cfg(feature = "tokio")]
+            Self::Join(error) => write!(f, "fetch task failed: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for DataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "fetch")]
+            Self::Request(error) => Some(error),
+            Self::MissingScript => None,
+            Self::Parse(error) => Some(error),
+            Self::InvalidBoard(error) => Some(error),
+            Self::Io(error) => Some(error),
+            #[cfg(feature = "tokio")]
+            Self::Join(error) => Some(error),
+        }
+    }
+}
+
+/// The old name for `DataError`, kept so existing code still builds.
+#[deprecated(note = "use `DataError`")]
+pub type GameDataError = DataError;
+
+#[cfg(feature = "fetch")]
+impl From<reqwest::Error> for DataError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Request(error)
+    }
+}
+
+impl From<SidesError> for DataError {
+    fn from(error: SidesError) -> Self {
+        Self::InvalidBoard(error)
+    }
+}
+
+impl From<serde_json::Error> for DataError {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl From<io::Error> for DataError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<tokio::task::JoinError> for DataError {
+    fn from(error: tokio::task::JoinError) -> Self {
+        Self::Join(error)
+    }
+}
+
+/// Somewhere to get the game data from.
+pub trait GameSource {
+    /// Gets the game data for the current day.
+    fn fetch_today(&self) -> Result<GameData, DataError>;
+}
+
+/// Gets the game data by scraping the NYT game website.
+#[cfg(feature = "fetch")]
+#[derive(Clone, Debug)]
+pub struct NytSource {
+    client: blocking::Client,
+}
+
+#[cfg(feature = "fetch")]
+impl NytSource {
+    pub fn new() -> Result<Self, DataError> {
+        let client = blocking::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?;
+        Ok(Self::with_client(client))
+    }
+
+    /// Uses the given client, which is useful when it needs a proxy, custom headers, or other settings.
+    pub fn with_client(client: blocking::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl GameSource for NytSource {
+    fn fetch_today(&self) -> Result<GameData, DataError> {
+        let html_content = self.client.get(GAME_URL).send()?.error_for_status()?.text()?;
+        parse_game_data(&html_content)
+    }
+}
+
+/// Gets the game data from another source, keeping each day's data in a directory as `<date>.json` and
+/// reading it from there instead while the newest saved game hasn't expired, going by its `expiration`.
+#[derive(Clone, Debug)]
+pub struct CachedSource<S> {
+    source: S,
+    dir: PathBuf,
+}
+
+impl<S: GameSource> CachedSource<S> {
+    pub fn new(source: S, dir: impl Into<PathBuf>) -> Self {
+        Self { source, dir: dir.into() }
+    }
+
+    /// Gets the file the game data for the given date is kept in.
+    fn path(&self, date: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", date))
+    }
+
+    /// Gets the newest saved game data that can be read, if it hasn't expired at the given time. Only files
+    /// named after a date are looked at, and files that can't be read or parsed are skipped, so a broken file
+    /// only means the data is fetched again.
+    fn cached(&self, now: SystemTime) -> Option<GameData> {
+        let mut paths = fs::read_dir(&self.dir)
+            .ok()?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .filter(|path| path.file_stem().and_then(|stem| stem.to_str()).is_some_and(is_date))
+            .collect::<Vec<PathBuf>>();
+        // The file names are dates, so the newest sorts last.
+        paths.sort();
+
+        let game_data = paths.iter().rev().find_map(|path| load_from_file(path).ok())?;
+        let now = now.duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+        (now < game_data.expiration as u64).then_some(game_data)
+    }
+
+    /// Same as `fetch_today`, as if it were the given time.
+    fn fetch_at(&self, now: SystemTime) -> Result<GameData, DataError> {
+        if let Some(game_data) = self.cached(now) {
+            return Ok(game_data);
+        }
+
+        let game_data = self.source.fetch_today()?;
+        fs::create_dir_all(&self.dir)?;
+        save_to_file(&game_data, &self.path(&game_data.date))?;
+        Ok(game_data)
+    }
+}
+
+impl<S: GameSource> GameSource for CachedSource<S> {
+    fn fetch_today(&self) -> Result<GameData, DataError> {
+        self.fetch_at(SystemTime::now())
+    }
+}
+
+/// Checks if the given string is a date in the same format as `GameData::date`, like `2024-03-27`.
+fn is_date(s: &str) -> bool {
+    s.len() == 10 && s.char_indices().all(|(i, c)| if i == 4 || i == 7 { c == '-' } else { c.is_ascii_digit() })
+}
+
+/// Reads game data saved with `save_to_file`.
+pub fn load_from_file(path: &Path) -> Result<GameData, DataError> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Saves game data as JSON, so the day's game can be loaded again later with `load_from_file`. The data is
+/// written to a temporary file next to `path` first and then renamed, so an interrupted write never leaves a
+/// partial file at `path`.
+pub fn save_to_file(game_data: &GameData, path: &Path) -> Result<(), DataError> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    fs::write(&temp, serde_json::to_string(game_data)?)?;
+    fs::rename(&temp, path)?;
+    Ok(())
+}
+
+/// Gets the game data for the current day by scraping the game website, keeping it in the given directory
+/// with `CachedSource` so it's only fetched once a day.
+#[cfg(feature = "fetch")]
+pub fn get_data_cached(dir: &Path) -> Result<GameData, DataError> {
+    CachedSource::new(NytSource::new()?, dir).fetch_today()
+}
+
 /// Gets the game data for the current day by scraping the game website.
-pub fn get_data() -> Option<GameData> {
-    let response = blocking::get("https://www.nytimes.com/puzzles/letter-boxed");
-    let html_content = response.unwrap().text().unwrap();
-    let document = scraper::Html::parse_document(&html_content);
+/// This blocks, so it mustn't be called from async code; use `get_data_async` there.
+#[cfg(feature = "fetch")]
+pub fn get_data() -> Result<GameData, DataError> {
+    NytSource::new()?.fetch_today()
+}
+
+/// Gets the game data for the current day in the same way as `get_data`, without blocking.
+/// It has to be awaited on a Tokio runtime, which reqwest's async client runs on.
+#[cfg(feature = "fetch")]
+pub async fn get_data_async() -> Result<GameData, DataError> {
+    let client = reqwest::Client::builder().user_agent(USER_AGENT).build()?;
+    let html_content = client.get(GAME_URL).send().await?.error_for_status()?.text().await?;
+    parse_game_data(&html_content)
+}
+
+/// Gets the game data for the current day by scraping the game website with the given client.
+/// Useful when the client needs a proxy, custom headers, or other settings.
+#[cfg(feature = "fetch")]
+pub fn get_data_with_client(client: &blocking::Client) -> Result<GameData, DataError> {
+    NytSource::with_client(client.clone()).fetch_today()
+}
+
+/// Gets the game data for the current day by running `get_data` on the given runtime's blocking thread pool,
+/// so it can be awaited from async code without blocking the runtime. Gives the same errors as `get_data`, or
+/// `DataError::Join` if the blocking task didn't finish.
+#[cfg(feature = "tokio")]
+pub async fn get_data_blocking_on(handle: &tokio::runtime::Handle) -> Result<GameData, DataError> {
+    handle.spawn_blocking(get_data).await?
+}
+
+/// Parses the game data out of the game website's HTML. If more than one script sets `window.gameData`,
+/// the first one that parses is used.
+#[cfg(feature = "fetch")]
+pub fn parse_game_data(html_content: &str) -> Result<GameData, DataError> {
+    const PREFIX: &str = "window.gameData = ";
+
+    let document = scraper::Html::parse_document(html_content);
     let scripts_selector = scraper::Selector::parse("script[type=\"text/javascript\"]").unwrap();
-    let mut scripts = document.select(&scripts_selector);
+    let mut last_error = None;
 
-    if let Some(game_data) = scripts.find(|s| s.inner_html().contains("window.gameData")) {
-        let start_len = "window.gameData = ".len();
-        let game_data = &game_data.inner_html()[start_len..];
-        match serde_json::from_str::<GameData>(game_data) {
+    for script in document.select(&scripts_selector) {
+        let script = script.inner_html();
+        let Some(start) = script.find(PREFIX) else {
+            continue;
+        };
+
+        match serde_json::from_str::<GameData>(&script[start + PREFIX.len()..]) {
             Ok(game_data) => {
-                return Some(game_data);
+                return Ok(game_data);
             },
             Err(error) => {
-                eprintln!("error = {:?}", error);
+                last_error = Some(error);
             }
         }
     }
-    
-    None
+
+    Err(last_error.map_or(DataError::MissingScript, DataError::Parse))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME_DATA: &str = r#"{"date":"2024-03-27","dictionary":["OKRA","ADJECTIVAL"],"editor":"Sam Ezersky","editorImage":"","expiration":1711598400,"id":1234,"isFree":false,"ourSolution":["OKRA","ADJECTIVAL"],"par":5,"printDate":"2024-03-27","sides":["DKI","JTA","CLV","ERO"],"yesterdaysSides":["ABC","DEF","GHI","JKL"],"yesterdaysSolution":["ABC"]}"#;
+
+    #[cfg(feature = "fetch")]
+    fn page(scripts: &[&str]) -> String {
+        let scripts = scripts
+            .iter()
+            .map(|s| format!("<script type=\"text/javascript\">{}</script>", s))
+            .collect::<String>();
+        format!("<html><head>{}</head><body></body></html>", scripts)
+    }
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_parse_game_data() {
+        let game_data = parse_game_data(&page(&[&format!("window.gameData = {}", GAME_DATA)])).unwrap();
+        assert_eq!(game_data.sides, ["DKI", "JTA", "CLV", "ERO"].map(String::from));
+        assert_eq!(game_data.par, 5);
+    }
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_parse_game_data_skips_decoy() {
+        let html = page(&[
+            "window.gameData = null",
+            "if (window.gameData) {}",
+            &format!("window.gameData = {}", GAME_DATA),
+        ]);
+        assert_eq!(parse_game_data(&html).unwrap().id, 1234);
+
+        assert!(matches!(parse_game_data(&page(&["window.gameData = null"])), Err(DataError::Parse(_))));
+        assert!(matches!(parse_game_data(&page(&["var x = 1"])), Err(DataError::MissingScript)));
+    }
+
+    /// A page laid out like the game website, with other scripts before and after the game data.
+    #[cfg(feature = "fetch")]
+    const PAGE_FIXTURE: &str = include_str!("../tests/fixtures/letter_boxed.html");
+
+    #[test]
+    #[cfg(feature = "fetch")]
+    fn test_parse_page_fixture() {
+        let game_data = parse_game_data(PAGE_FIXTURE).unwrap();
+        assert_eq!(game_data.id, 1401);
+        assert_eq!(game_data.sides, ["DKI", "JTA", "CLV", "ERO"].map(String::from));
+        assert_eq!(game_data.yesterdaysSides, ["UMG", "ROY", "PAN", "LIE"].map(String::from));
+        assert_eq!(game_data.par, 5);
+        assert_eq!(game_data.dictionary.len(), 62);
+        assert_eq!(game_data.ourSolution, vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]);
+    }
+
+    /// Gives the same game data every time, counting how many times it was asked.
+    struct CountingSource(GameData, std::cell::Cell<usize>);
+
+    impl GameSource for CountingSource {
+        fn fetch_today(&self) -> Result<GameData, DataError> {
+            self.1.set(self.1.get() + 1);
+            Ok(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn test_cached_source() {
+        let dir = std::env::temp_dir().join(format!("letter_boxed_bot_cache_{}", std::process::id()));
+        let mut game_data = serde_json::from_str::<GameData>(GAME_DATA).unwrap();
+        game_data.expiration = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as usize + 3600;
+
+        let cached = CachedSource::new(CountingSource(game_data.clone(), Default::default()), &dir);
+        assert_eq!(cached.fetch_today().unwrap().id, 1234);
+        assert_eq!(cached.fetch_today().unwrap().id, 1234);
+        assert_eq!(cached.source.1.get(), 1);
+
+        let path = dir.join(format!("{}.json", game_data.date));
+        let loaded = load_from_file(&path).unwrap();
+        assert_eq!(loaded.sides, game_data.sides);
+        assert_eq!(loaded.dictionary, game_data.dictionary);
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(load_from_file(&path), Err(DataError::Io(_))));
+    }
+
+    #[test]
+    fn test_cached_source_after_utc_midnight() {
+        let dir = std::env::temp_dir().join(format!("letter_boxed_bot_cache_midnight_{}", std::process::id()));
+        // The game for 2024-03-27 expires at midnight US Eastern, four hours into the 28th in UTC.
+        let game_data = serde_json::from_str::<GameData>(GAME_DATA).unwrap();
+        let at = |secs| UNIX_EPOCH + std::time::Duration::from_secs(secs);
+        let late_evening = at(game_data.expiration as u64 - 2 * 3600);
+
+        let cached = CachedSource::new(CountingSource(game_data.clone(), Default::default()), &dir);
+        assert_eq!(cached.fetch_at(late_evening).unwrap().date, "2024-03-27");
+        assert_eq!(cached.fetch_at(late_evening).unwrap().date, "2024-03-27");
+        assert_eq!(cached.source.1.get(), 1);
+        assert!(dir.join("2024-03-27.json").exists());
+
+        cached.fetch_at(at(game_data.expiration as u64)).unwrap();
+        assert_eq!(cached.source.1.get(), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_cached_source_corrupt_file() {
+        let dir = std::env::temp_dir().join(format!("letter_boxed_bot_cache_corrupt_{}", std::process::id()));
+        let game_data = serde_json::from_str::<GameData>(GAME_DATA).unwrap();
+        let early = UNIX_EPOCH + std::time::Duration::from_secs(game_data.expiration as u64 - 3600);
+
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("2024-03-27.json"), &GAME_DATA[..GAME_DATA.len() / 2]).unwrap();
+        fs::write(dir.join("notes.json"), "not game data").unwrap();
+
+        let cached = CachedSource::new(CountingSource(game_data.clone(), Default::default()), &dir);
+        assert_eq!(cached.fetch_at(early).unwrap().id, 1234);
+        assert_eq!(cached.source.1.get(), 1);
+
+        // The broken file was replaced, so it's read from the cache now.
+        assert_eq!(cached.fetch_at(early).unwrap().id, 1234);
+        assert_eq!(cached.source.1.get(), 1);
+        assert!(!dir.join("2024-03-27.json.tmp").exists());
+
+        // A broken file for a later date is skipped in favor of the newest one that reads.
+        fs::write(dir.join("2024-03-28.json"), "{").unwrap();
+        assert_eq!(cached.fetch_at(early).unwrap().id, 1234);
+        assert_eq!(cached.source.1.get(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_get_data_async_is_send() {
+        // Bots usually spawn the fetch onto a multithreaded runtime, which needs the future to be `Send`.
+        fn assert_send<T: Send>(_: &T) {}
+        assert_send(&get_data_async());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_join_error() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let task = runtime.spawn(std::future::pending::<()>());
+        task.abort();
+
+        let error = DataError::from(runtime.block_on(task).unwrap_err());
+        assert!(matches!(error, DataError::Join(_)));
+        assert!(error.to_string().starts_with("fetch task failed: "));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parsed_date() {
+        let mut game_data = serde_json::from_str::<GameData>(GAME_DATA).unwrap();
+        assert_eq!(game_data.parsed_date(), Ok(chrono::NaiveDate::from_ymd_opt(2024, 3, 27).unwrap()));
+        assert_eq!(game_data.parsed_print_date(), game_data.parsed_date());
+
+        game_data.date = "March 27, 2024".to_string();
+        assert!(game_data.parsed_date().is_err());
+        assert_eq!(game_data.date, "March 27, 2024");
+    }
 }
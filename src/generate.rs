@@ -0,0 +1,94 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{Game, Sides};
+
+/// How many word pairs to try before giving up.
+const MAX_ATTEMPTS: usize = 1000;
+/// How many letter arrangements to try for each word pair.
+const MAX_ARRANGEMENTS: usize = 100;
+
+/// The reason a board couldn't be generated.
+#[derive(Debug)]
+pub enum GenerateError {
+    /// No solvable board was found after the given number of attempts.
+    RetriesExhausted(usize),
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::RetriesExhausted(attempts) => write!(f, "no solvable board found after {} attempts", attempts),
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+impl Sides {
+    /// Generates a random board that has at least one solution using words from `dict`.
+    /// The board is built around a pair of chained words that together use exactly twelve letters, and is
+    /// only returned once that pair has been replayed as a winning game.
+    pub fn random_solvable(rng: &mut impl Rng, dict: &[String]) -> Result<Self, GenerateError> {
+        for _ in 0..MAX_ATTEMPTS {
+            let Some(first) = dict.choose(rng) else {
+                break;
+            };
+            let first_letters = first.chars().collect::<HashSet<char>>();
+            if first_letters.len() >= 12 {
+                continue;
+            }
+
+            let last = first.chars().last().unwrap();
+            let seconds = dict
+                .iter()
+                .filter(|w| w.starts_with(last) && first_letters.union(&w.chars().collect()).count() == 12)
+                .collect::<Vec<&String>>();
+            let Some(second) = seconds.choose(rng) else {
+                continue;
+            };
+
+            let mut letters = first_letters.union(&second.chars().collect()).copied().collect::<Vec<char>>();
+            letters.sort();
+
+            for _ in 0..MAX_ARRANGEMENTS {
+                letters.shuffle(rng);
+                let sides = Sides::new(
+                    [letters[0], letters[1], letters[2]],
+                    [letters[3], letters[4], letters[5]],
+                    [letters[6], letters[7], letters[8]],
+                    [letters[9], letters[10], letters[11]],
+                );
+
+                let mut game = Game::new(sides);
+                if game.try_play_word(first).is_ok() && game.try_play_word(second).is_ok() && game.check_win() {
+                    return Ok(game.sides().clone());
+                }
+            }
+        }
+
+        Err(GenerateError::RetriesExhausted(MAX_ATTEMPTS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{words, Solver};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random_solvable() {
+        let dict = words::WORDS.iter().map(|w| w.to_string()).collect::<Vec<String>>();
+        let sides = Sides::random_solvable(&mut StdRng::seed_from_u64(0), &dict).unwrap();
+        let again = Sides::random_solvable(&mut StdRng::seed_from_u64(0), &dict).unwrap();
+        assert_eq!(sides.top(), again.top());
+        assert_eq!(sides.left(), again.left());
+
+        assert!(!Solver::solve(&Game::new(sides)).is_empty());
+    }
+}
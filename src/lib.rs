@@ -1,12 +1,34 @@
-mod words;
+pub mod words;
+pub mod batch;
 pub mod game_data;
+#[cfg(feature = "gen")]
+mod generate;
+mod strategy;
 
+#[cfg(feature = "gen")]
+pub use generate::GenerateError;
+pub use strategy::{BeamSolver, ExhaustiveSolver, GreedySolver, Solve};
+
+use game_data::{DataError, GameData, GameSource};
+#[cfg(feature = "fetch")]
+use game_data::NytSource;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use std::collections::HashSet;
-use std::sync::Mutex;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
-#[derive(Debug)]
+/// A board slot that any letter not otherwise on the board can be played on. A letter played on it covers the
+/// wildcard itself, so a game is won once every letter on the board and the wildcard have been used. A board can
+/// have at most one wildcard, since each slot's letter must be unique.
+#[cfg(feature = "wildcards")]
+pub const WILDCARD: char = '*';
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Side {
     Top,
     Right,
@@ -14,218 +36,1189 @@ pub enum Side {
     Left,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(try_from = "[String; 4]", into = "[String; 4]")]
 pub struct Sides {
-    top: [char; 3],
-    right: [char; 3],
-    bottom: [char; 3],
-    left: [char; 3],
+    top: Vec<char>,
+    right: Vec<char>,
+    bottom: Vec<char>,
+    left: Vec<char>,
     all_chars: HashSet<char>,
+    letter_sides: HashMap<char, Side>,
+    /// A bit for each letter on the board, with A as the lowest bit, if every letter is between A and Z.
+    letter_mask: Option<u32>,
+    /// A bit for each letter on the board, numbered in alphabetical order, for tracking which are used.
+    letter_bits: HashMap<char, u32>,
 }
 
 impl Sides {
     pub fn new(top: [char; 3], right: [char; 3], bottom: [char; 3], left: [char; 3]) -> Self {
+        Self::from_letters([top, right, bottom, left].map(Vec::from))
+    }
+
+    /// Builds the sides from their letters, which may be any number per side. Nothing is checked.
+    fn from_letters(sides: [Vec<char>; 4]) -> Self {
+        let [top, right, bottom, left] = sides;
+        // Inserted in reverse so that a repeated letter maps to its first side.
+        let letter_sides = [(Side::Left, &left), (Side::Bottom, &bottom), (Side::Right, &right), (Side::Top, &top)]
+            .into_iter()
+            .flat_map(|(side, letters)| letters.iter().map(move |&letter| (letter, side)))
+            .collect();
+        let all_chars = [&top, &right, &bottom, &left].into_iter().flatten().copied().collect::<HashSet<char>>();
+        let letter_mask = all_chars
+            .iter()
+            .try_fold(0, |mask, &letter| letter.is_ascii_uppercase().then(|| mask | 1 << (letter as u8 - b'A')));
+        let mut sorted = all_chars.iter().copied().collect::<Vec<char>>();
+        sorted.sort();
+        let letter_bits = sorted.into_iter().zip(0..MAX_BOARD_LETTERS as u32).map(|(letter, i)| (letter, 1 << i)).collect();
+
         Self {
             top, right, bottom, left,
-            all_chars: HashSet::from([top[0], top[1], top[2], right[0], right[1], right[2], bottom[0], bottom[1], bottom[2], left[0], left[1], left[2]]),
+            all_chars,
+            letter_sides,
+            letter_mask,
+            letter_bits,
         }
     }
 
+    /// Builds the sides from a string for each side, checking that each side has three letters, or that all
+    /// four have the same number, and that no letter is repeated.
+    pub fn try_new(top: &str, right: &str, bottom: &str, left: &str) -> Result<Self, SidesError> {
+        Self::try_from([top, right, bottom, left].map(String::from))
+    }
+
+    /// Same as `try_new`, panicking if the sides aren't a valid board.
     pub fn from_str(top: &str, right: &str, bottom: &str, left: &str) -> Self {
-        Self::new(
-            top.chars().collect::<Vec<char>>().try_into().unwrap(),
-            right.chars().collect::<Vec<char>>().try_into().unwrap(),
-            bottom.chars().collect::<Vec<char>>().try_into().unwrap(),
-            left.chars().collect::<Vec<char>>().try_into().unwrap(),
-        )
+        Self::try_new(top, right, bottom, left).unwrap_or_else(|error| panic!("invalid sides: {}", error))
     }
 
+    /// Same as `from_str`, with the sides in an array.
     pub fn from_sides(sides: &[String; 4]) -> Self {
-        Self::new(
-            sides[0].chars().collect::<Vec<char>>().try_into().unwrap(),
-            sides[1].chars().collect::<Vec<char>>().try_into().unwrap(),
-            sides[2].chars().collect::<Vec<char>>().try_into().unwrap(),
-            sides[3].chars().collect::<Vec<char>>().try_into().unwrap(),
-        )
+        Self::try_from(sides.clone()).unwrap_or_else(|error| panic!("invalid sides: {}", error))
     }
 
-    pub fn top(&self) -> [char; 3] {
-        self.top
+    /// Builds the sides from a 12-letter string read clockwise from the top-left corner: the top side
+    /// left to right, the right side top to bottom, the bottom side right to left, and the left side
+    /// bottom to top.
+    pub fn from_clockwise(s: &str) -> Result<Self, SidesError> {
+        let letters = s.chars().collect::<Vec<char>>();
+        if letters.len() != 12 {
+            return Err(SidesError::WrongLetterCount(letters.len()));
+        }
+
+        if let Some(letter) = find_duplicate(&letters) {
+            return Err(SidesError::DuplicateLetter(letter));
+        }
+
+        Ok(Self::new(
+            [letters[0], letters[1], letters[2]],
+            [letters[3], letters[4], letters[5]],
+            [letters[8], letters[7], letters[6]],
+            [letters[11], letters[10], letters[9]],
+        ))
     }
 
-    pub fn right(&self) -> [char; 3] {
-        self.right
+    /// Builds the sides from a board as it's usually shared, like `dki-jta-clv-ero` or `DKI JTA CLV ERO`: the
+    /// four sides in the same order as `from_sides`, split by dashes, spaces, or commas, in any case. A single
+    /// group is read as twelve letters clockwise, as with `from_clockwise`.
+    pub fn from_shareable(s: &str) -> Result<Self, SidesError> {
+        let s = s.to_uppercase();
+        let groups = s
+            .split(|c: char| c.is_whitespace() || c == '-' || c == ',')
+            .filter(|group| !group.is_empty())
+            .collect::<Vec<&str>>();
+
+        match groups[..] {
+            [letters] => Self::from_clockwise(letters),
+            [top, right, bottom, left] => Self::try_new(top, right, bottom, left),
+            _ => Err(SidesError::WrongSideCount(groups.len())),
+        }
     }
 
-    pub fn bottom(&self) -> [char; 3] {
-        self.bottom
+    /// Gets the board in the form read by `from_shareable`, like `DKI-JTA-CLV-ERO`.
+    pub fn to_shareable(&self) -> String {
+        self.to_strings().join("-")
     }
 
-    pub fn left(&self) -> [char; 3] {
-        self.left
+    /// Builds the sides from a JSON array of four strings, in the same format as `GameData::sides`, checking
+    /// them in the same way as `TryFrom<[String; 4]>`.
+    pub fn from_json_array(value: &serde_json::Value) -> Result<Self, serde_json::Error> {
+        Self::deserialize(value)
     }
 
-    /// Gets the side based on the given letter.
-    pub fn get_side(&self, letter: char) -> Option<Side> {
-        if !self.all_chars.contains(&letter) {
-            None
-        } else if self.top.contains(&letter) {
-            Some(Side::Top)
-        } else if self.right.contains(&letter) {
-            Some(Side::Right)
-        } else if self.bottom.contains(&letter) {
-            Some(Side::Bottom)
-        } else if self.left.contains(&letter) {
-            Some(Side::Left)
+    pub fn top(&self) -> &[char] {
+        &self.top
+    }
+
+    pub fn right(&self) -> &[char] {
+        &self.right
+    }
+
+    pub fn bottom(&self) -> &[char] {
+        &self.bottom
+    }
+
+    pub fn left(&self) -> &[char] {
+        &self.left
+    }
+
+    /// Gets the number of letters on each side.
+    pub fn side_len(&self) -> usize {
+        self.top.len()
+    }
+
+    /// Gets a copy of the letters of each side, in the same order as `from_sides`.
+    fn letters(&self) -> [Vec<char>; 4] {
+        [self.top.clone(), self.right.clone(), self.bottom.clone(), self.left.clone()]
+    }
+
+    /// Gets a copy of the board with the given side's letters replaced, checking that the side has as many
+    /// letters as the others and that no letter is repeated.
+    pub fn with_side(&self, side: Side, letters: impl Into<Vec<char>>) -> Result<Self, SidesError> {
+        let mut sides = self.letters();
+        sides[side as usize] = letters.into();
+
+        check_side_lengths(&sides, self.side_len())?;
+        if let Some(letter) = find_duplicate(&sides.concat()) {
+            return Err(SidesError::DuplicateLetter(letter));
+        }
+
+        Ok(Self::from_letters(sides))
+    }
+
+    /// Gets a copy of the board with the letters of two sides swapped.
+    pub fn swap_side(&self, a: Side, b: Side) -> Self {
+        let mut sides = self.letters();
+        sides.swap(a as usize, b as usize);
+        Self::from_letters(sides)
+    }
+
+    /// Gets the letters of each side as strings, in the same order as `from_sides`.
+    pub fn to_strings(&self) -> [String; 4] {
+        self.letters().map(|side| side.into_iter().collect())
+    }
+
+    /// Gets a string that identifies the board regardless of the order of its sides, or of the letters
+    /// within each side, since neither affects which words can be played. Each side's letters are sorted,
+    /// and the sides are sorted and joined with `-`.
+    pub fn fingerprint(&self) -> String {
+        let mut sides = self.to_strings().map(|side| {
+            let mut letters = side.chars().collect::<Vec<char>>();
+            letters.sort();
+            letters.into_iter().collect::<String>()
+        });
+        sides.sort();
+        sides.join("-")
+    }
+
+    /// Gets a number that identifies the board in the same way as `fingerprint`.
+    /// Boards of up to twelve letters using only A to Z are packed 5 bits per letter into the low 60 bits, so
+    /// different boards never share a signature. Other boards, including larger ones, are hashed with FNV-1a and
+    /// have the top bit set, so they can only collide with each other.
+    pub fn signature(&self) -> u64 {
+        let fingerprint = self.fingerprint();
+
+        if 5 * self.all_chars.len() <= 60 && fingerprint.chars().all(|c| c == '-' || c.is_ascii_uppercase()) {
+            fingerprint
+                .bytes()
+                .filter(|&b| b != b'-')
+                .fold(0, |signature, b| (signature << 5) | (b - b'A') as u64)
         } else {
-            None
+            let hash = fingerprint
+                .bytes()
+                .fold(0xcbf29ce484222325, |hash: u64, b| (hash ^ b as u64).wrapping_mul(0x100000001b3));
+            hash | 1 << 63
+        }
+    }
+
+    /// Gets the side based on the given letter. A letter played on the wildcard is on the wildcard's side.
+    pub fn get_side(&self, letter: char) -> Option<Side> {
+        self.letter_sides
+            .get(&letter)
+            .or_else(|| self.slot(letter).and_then(|slot| self.letter_sides.get(&slot)))
+            .copied()
+    }
+
+    /// Gets the board letter the given letter is played on, which is the letter itself if it's on the board,
+    /// or the wildcard if there is one.
+    fn slot(&self, letter: char) -> Option<char> {
+        if self.all_chars.contains(&letter) {
+            return Some(letter);
+        }
+
+        #[cfg(feature = "wildcards")]
+        if self.all_chars.contains(&WILDCARD) {
+            return Some(WILDCARD);
+        }
+
+        None
+    }
+
+    /// Gets the board letters the given word is played on.
+    fn slots(&self, word: &str) -> HashSet<char> {
+        word.chars().filter_map(|letter| self.slot(letter)).collect()
+    }
+
+    /// Gets the bits of the board letters the given word is played on, as numbered by `letter_bits`.
+    fn word_bits(&self, word: &str) -> u32 {
+        word.chars()
+            .filter_map(|letter| self.slot(letter).and_then(|slot| self.letter_bits.get(&slot)))
+            .fold(0, |bits, bit| bits | bit)
+    }
+
+    /// Gets the bits of every board letter, as numbered by `letter_bits`.
+    fn full_bits(&self) -> u32 {
+        self.letter_bits.values().fold(0, |bits, bit| bits | bit)
+    }
+
+    /// Checks if every letter of the word is on the board. When the board only uses the letters A to Z, this is
+    /// a bitmask check, which is much faster than looking up each letter's side.
+    pub fn has_only_board_letters(&self, word: &str) -> bool {
+        match self.letter_mask {
+            Some(mask) => word.bytes().all(|b| b.is_ascii_uppercase() && mask & 1 << (b - b'A') != 0),
+            None => word.chars().all(|letter| self.slot(letter).is_some()),
+        }
+    }
+
+    /// Gets the letters that can follow the given letter in a word, which are the letters on every other side.
+    /// A letter that isn't on the board has none.
+    pub fn legal_next(&self, letter: char) -> Vec<char> {
+        match self.get_side(letter) {
+            Some(side) => [Side::Top, Side::Right, Side::Bottom, Side::Left]
+                .into_iter()
+                .filter(|&other| other != side)
+                .flat_map(|other| self.get_letters(&other).iter().copied())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Gets the letters that can be reached from the start letter in at most `depth` moves, following
+    /// `legal_next`. The start letter is included if it's on the board.
+    pub fn reachable(&self, start: char, depth: usize) -> HashSet<char> {
+        if self.get_side(start).is_none() {
+            return HashSet::new();
         }
+
+        let mut reached = HashSet::from([start]);
+        let mut frontier = vec![start];
+        for _ in 0..depth {
+            frontier = frontier
+                .iter()
+                .flat_map(|&letter| self.legal_next(letter))
+                .filter(|&letter| reached.insert(letter))
+                .collect();
+            if frontier.is_empty() {
+                break;
+            }
+        }
+        reached
+    }
+
+    /// Gets the letters on the board in alphabetical order, for display where `all_chars` would list them in a
+    /// different order each time.
+    pub fn sorted_letters(&self) -> Vec<char> {
+        let mut letters = self.all_chars.iter().copied().collect::<Vec<char>>();
+        letters.sort();
+        letters
+    }
+
+    /// Gets the side of every letter on the board.
+    pub fn letter_sides(&self) -> &HashMap<char, Side> {
+        &self.letter_sides
     }
 
     /// Gets the letters for the given side.
-    pub fn get_letters(&self, side: &Side) -> [char; 3] {
+    pub fn get_letters(&self, side: &Side) -> &[char] {
         match side {
-            Side::Top => self.top,
-            Side::Right => self.right,
-            Side::Bottom => self.bottom,
-            Side::Left => self.left,
+            Side::Top => &self.top,
+            Side::Right => &self.right,
+            Side::Bottom => &self.bottom,
+            Side::Left => &self.left,
+        }
+    }
+
+    /// Returns a string representation of the board, with the top and bottom sides spaced out between the left and
+    /// right sides.
+    pub fn format_board(&self) -> String {
+        let mut output = String::new();
+
+        let spaced = |letters: &[char]| letters.iter().map(char::to_string).collect::<Vec<String>>().join(" ");
+        let gap = " ".repeat(2 * self.side_len() + 1);
+
+        output.push_str(&format!("  {}  \n", spaced(self.top())));
+        for (left, right) in self.left().iter().zip(self.right()) {
+            output.push_str(&format!("{}{}{} \n", left, gap, right));
+        }
+        output.push_str(&format!("  {}  ", spaced(self.bottom())));
+
+        output
+    }
+}
+
+impl fmt::Display for Sides {
+    /// Writes the board as given by `format_board`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.format_board())
+    }
+}
+
+impl TryFrom<[String; 4]> for Sides {
+    type Error = SidesError;
+
+    /// Builds the sides from strings in the same order as `from_sides`, checking that each side has three
+    /// letters, or that all four have the same number, and that no letter is repeated.
+    fn try_from(sides: [String; 4]) -> Result<Self, Self::Error> {
+        let sides = sides.map(|side| side.chars().collect::<Vec<char>>());
+        check_side_lengths(&sides, usual_side_length(&sides))?;
+
+        if let Some(letter) = find_duplicate(&sides.concat()) {
+            return Err(SidesError::DuplicateLetter(letter));
         }
+
+        Ok(Self::from_letters(sides))
     }
 }
 
+impl TryFrom<&GameData> for Sides {
+    type Error = SidesError;
 
-#[derive(Clone, Debug)]
+    /// Builds the sides for the day's game, checking them in the same way as `TryFrom<[String; 4]>`.
+    fn try_from(game_data: &GameData) -> Result<Self, Self::Error> {
+        Self::try_from(game_data.sides.clone())
+    }
+}
+
+impl From<Sides> for [String; 4] {
+    fn from(sides: Sides) -> Self {
+        sides.to_strings()
+    }
+}
+
+/// The most letters a board can have, so that the used letters fit in a `u32` bitmask.
+pub const MAX_BOARD_LETTERS: usize = 32;
+
+/// Gets the number of letters most of the sides have, going by the first side on a tie, so that a single side
+/// of the wrong length is the one reported.
+fn usual_side_length(sides: &[Vec<char>; 4]) -> usize {
+    let count = |len: usize| sides.iter().filter(|side| side.len() == len).count();
+    sides.iter().map(Vec::len).rev().max_by_key(|&len| count(len)).unwrap_or(0)
+}
+
+/// Checks that every side has `expected` letters, reporting the first side that doesn't.
+fn check_side_lengths(sides: &[Vec<char>; 4], expected: usize) -> Result<(), SidesError> {
+    let order = [Side::Top, Side::Right, Side::Bottom, Side::Left];
+    if expected == 0 {
+        let side = order.into_iter().zip(sides).find(|(_, letters)| letters.is_empty()).map_or(Side::Top, |(side, _)| side);
+        return Err(SidesError::WrongSideLength { side, got: 0 });
+    }
+
+    if let Some((side, letters)) = order.into_iter().zip(sides).find(|(_, letters)| letters.len() != expected) {
+        return Err(SidesError::MismatchedSideLength { side, expected, got: letters.len() });
+    }
+    if 4 * expected > MAX_BOARD_LETTERS {
+        return Err(SidesError::TooManyLetters(4 * expected));
+    }
+
+    Ok(())
+}
+
+/// Gets the first letter that appears a second time.
+fn find_duplicate(letters: &[char]) -> Option<char> {
+    let mut seen = HashSet::new();
+    letters.iter().find(|&&letter| !seen.insert(letter)).copied()
+}
+
+/// The reason a board can't be built.
+#[derive(Debug)]
+pub enum SidesError {
+    /// The board doesn't have twelve letters.
+    WrongLetterCount(usize),
+    /// A side has a number of letters outside `1..=MAX_BOARD_LETTERS / 4`, which at the moment only happens when
+    /// most of the sides are empty.
+    WrongSideLength { side: Side, got: usize },
+    /// A side has a different number of letters from the rest of the board, which is measured by most of the
+    /// sides, or by the existing board when a side is replaced.
+    MismatchedSideLength { side: Side, expected: usize, got: usize },
+    /// The letter appears more than once on the board.
+    DuplicateLetter(char),
+    /// The board has more than `MAX_BOARD_LETTERS` letters.
+    TooManyLetters(usize),
+    /// The board doesn't have four sides.
+    WrongSideCount(usize),
+}
+
+impl fmt::Display for SidesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::WrongLetterCount(count) => write!(f, "expected 12 letters, got {}", count),
+            Self::WrongSideLength { side, got } => write!(
+                f,
+                "expected 1 to {} letters on the {} side, got {}",
+                MAX_BOARD_LETTERS / 4,
+                format!("{:?}", side).to_lowercase(),
+                got
+            ),
+            Self::MismatchedSideLength { side, expected, got } => {
+                write!(f, "expected {} letters on the {} side, got {}", expected, format!("{:?}", side).to_lowercase(), got)
+            }
+            Self::DuplicateLetter(letter) => write!(f, "'{}' appears more than once", letter),
+            Self::TooManyLetters(count) => write!(f, "expected at most {} letters, got {}", MAX_BOARD_LETTERS, count),
+            Self::WrongSideCount(count) => write!(f, "expected 4 sides, got {}", count),
+        }
+    }
+}
+
+impl std::error::Error for SidesError {}
+
+/// The fewest letters a word can have by default, matching the NYT rules.
+pub const MIN_WORD_LEN: usize = 3;
+
+/// The reason a word can't be played.
+#[derive(Debug)]
+pub enum WordRejection {
+    /// The word doesn't start with the last letter of the previous word.
+    NotStartingWithLastLetter { expected: char },
+    /// The letter is on the same side as the letter before it.
+    ConsecutiveSameSide { letter: char, side: Side },
+    /// The letter isn't on the board.
+    LetterNotOnBoard(char),
+    /// The word has fewer letters than the game's minimum word length.
+    TooShort { min_len: usize },
+    /// The word has more letters than the game's maximum word length.
+    TooLong { max_len: usize },
+    /// The word has already been played, and the game doesn't allow playing a word again.
+    AlreadyPlayed,
+}
+
+impl fmt::Display for WordRejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NotStartingWithLastLetter { expected } => write!(f, "word must start with '{}'", expected),
+            Self::ConsecutiveSameSide { letter, side } => write!(f, "'{}' is on the same side ({:?}) as the letter before it", letter, side),
+            Self::LetterNotOnBoard(letter) => write!(f, "'{}' is not on the board", letter),
+            Self::TooShort { min_len } => write!(f, "word must have at least {} letters", min_len),
+            Self::TooLong { max_len } => write!(f, "word must have at most {} letters", max_len),
+            Self::AlreadyPlayed => write!(f, "word has already been played"),
+        }
+    }
+}
+
+impl std::error::Error for WordRejection {}
+
+/// The old name for `WordRejection`, kept so existing code still builds.
+#[deprecated(note = "use `WordRejection`")]
+pub type WordError = WordRejection;
+
+/// The reason a sequence of words doesn't solve a game.
+#[derive(Debug)]
+pub enum SolutionError {
+    /// The word at the given index can't be played after the words before it.
+    InvalidWord { index: usize, reason: WordRejection },
+    /// Every word can be played, but the given number of letters are left unused.
+    NotWon { remaining: usize },
+}
+
+impl fmt::Display for SolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidWord { index, reason } => write!(f, "word {} can't be played: {}", index + 1, reason),
+            Self::NotWon { remaining } => write!(f, "{} letters are left unused", remaining),
+        }
+    }
+}
+
+impl std::error::Error for SolutionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidWord { reason, .. } => Some(reason),
+            Self::NotWon { .. } => None,
+        }
+    }
+}
+
+/// How many dictionary words a game accepts, and how many it rejects for each reason.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ValidationStats {
+    pub valid: usize,
+    pub not_starting_with_last_letter: usize,
+    pub too_short: usize,
+    pub letter_not_on_board: usize,
+    pub consecutive_same_side: usize,
+    pub too_long: usize,
+    pub already_played: usize,
+}
+
+/// The rules a game is played by. The defaults match the NYT game.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct Rules {
+    /// The fewest letters a word can have. Defaults to `MIN_WORD_LEN`.
+    pub min_word_len: usize,
+    /// The most letters a word can have, if limited. Defaults to `None`.
+    pub max_word_len: Option<usize>,
+    /// Whether a word can be played more than once. Defaults to `true`. The solver never plays a word again
+    /// either way, since doing so never covers a new letter.
+    pub allow_reuse: bool,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            min_word_len: MIN_WORD_LEN,
+            max_word_len: None,
+            allow_reuse: true,
+        }
+    }
+}
+
+/// A game is stored as its board, the words played and its dictionary if it isn't the bundled one, since the
+/// letter sets can be rebuilt from those.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(from = "StoredGame", into = "StoredGame")]
 pub struct Game {
-    sides: Sides,
+    /// Shared by clones, since the board never changes and the solver clones a game for every word it plays.
+    sides: Arc<Sides>,
     used_letters: HashSet<char>,
     remaining_letters: HashSet<char>,
+    /// The same letters as `used_letters`, as bits numbered by `Sides::word_bits`.
+    used_bits: u32,
+    previous_words: Vec<String>,
+    par: Option<usize>,
+    rules: Rules,
+    /// The words that can be played, or `None` for the bundled dictionary.
+    dictionary: Option<Arc<Vec<String>>>,
+    /// The dictionary words that fit the board, worked out the first time they're needed and shared by clones.
+    board_words: OnceLock<Arc<Vec<String>>>,
+    /// The same words as `board_words`, keyed by their length and sorted alphabetically, worked out the first time
+    /// they're needed and shared by clones.
+    words_by_length: OnceLock<Arc<BTreeMap<usize, Vec<String>>>>,
+}
+
+/// The dictionary is left out for the bundled one, so a game stored before dictionaries were saved loads with it.
+#[derive(Deserialize, Serialize)]
+struct StoredGame {
+    sides: Sides,
     previous_words: Vec<String>,
+    par: Option<usize>,
+    #[serde(default)]
+    rules: Rules,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    dictionary: Option<Vec<String>>,
+}
+
+impl From<StoredGame> for Game {
+    fn from(stored: StoredGame) -> Self {
+        let mut game = Self::new_with_rules(stored.sides, stored.rules);
+        if let Some(dictionary) = stored.dictionary {
+            game.set_dictionary(dictionary);
+        }
+        game.previous_words = stored.previous_words;
+        game.par = stored.par;
+        game.hydrate();
+        game
+    }
+}
+
+impl From<Game> for StoredGame {
+    fn from(game: Game) -> Self {
+        Self {
+            sides: Arc::unwrap_or_clone(game.sides),
+            previous_words: game.previous_words,
+            par: game.par,
+            rules: game.rules,
+            dictionary: game.dictionary.map(Arc::unwrap_or_clone),
+        }
+    }
+}
+
+impl TryFrom<&GameData> for Game {
+    type Error = SidesError;
+
+    /// Builds the day's game with its par and the day's dictionary, failing if the sides aren't a valid board.
+    /// The bundled dictionary is used if the day's one is empty.
+    fn try_from(game_data: &GameData) -> Result<Self, Self::Error> {
+        let mut game = Self::new(Sides::try_from(game_data)?);
+        game.set_par(Some(game_data.par));
+        if !game_data.dictionary.is_empty() {
+            game.set_dictionary(game_data.dictionary.clone());
+        }
+        Ok(game)
+    }
 }
 
 impl Game {
     pub fn new(sides: Sides) -> Self {
+        Self::new_with_rules(sides, Rules::default())
+    }
+
+    /// Creates a game played by the given rules.
+    pub fn new_with_rules(sides: Sides, rules: Rules) -> Self {
         let remaining_letters = sides.all_chars.clone();
         
         Self {
-            sides,
+            sides: Arc::new(sides),
             used_letters: HashSet::new(),
             remaining_letters,
+            used_bits: 0,
             previous_words: Vec::new(),
+            par: None,
+            rules,
+            dictionary: None,
+            board_words: OnceLock::new(),
+            words_by_length: OnceLock::new(),
         }
     }
 
-    /// Gets the game for the current day.
-    pub fn today() -> Option<Self> {
-        match game_data::get_data() {
-            Some(game_data) => {
-                let sides = Sides::from_sides(&game_data.sides);
-                Some(Self::new(sides))
+    /// Creates a game that plays words from the given dictionary instead of the bundled one.
+    /// The words are uppercased to match the board, so the dictionary can be in any case.
+    pub fn with_dictionary(sides: Sides, words: Vec<String>) -> Self {
+        let mut game = Self::new(sides);
+        game.set_dictionary(words);
+        game
+    }
+
+    /// Plays words from the given dictionary instead of the current one, uppercasing them like `with_dictionary`.
+    pub fn set_dictionary(&mut self, words: Vec<String>) {
+        let words = words.into_iter().map(|word| word.to_uppercase()).collect();
+        self.dictionary = Some(Arc::new(words));
+        self.board_words = OnceLock::new();
+        self.words_by_length = OnceLock::new();
+    }
+
+    /// Gets the words this game can play, regardless of the board.
+    fn dictionary(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        match &self.dictionary {
+            Some(words) => Box::new(words.iter().map(String::as_str)),
+            None => Box::new(words::WORDS.iter().copied()),
+        }
+    }
+
+    /// Gets the dictionary words that only use board letters and never use the same side twice in a row, which
+    /// can't change as the game is played. The rules and the previous word aren't checked.
+    /// Most words use a letter that isn't on the board, so those are filtered out first with a cheaper check.
+    fn board_words(&self) -> &[String] {
+        self.board_words.get_or_init(|| {
+            let fits = |w: &&str| self.sides.has_only_board_letters(w) && self.validate_sides(w).is_ok();
+            let words = match &self.dictionary {
+                Some(words) => words.par_iter().map(String::as_str).filter(fits).map(String::from).collect(),
+                None => words::WORDS.par_iter().copied().filter(fits).map(String::from).collect(),
+            };
+            Arc::new(words)
+        })
+    }
+
+    /// Checks if every letter that is left appears in a word that fits the board and the rules. If one doesn't,
+    /// the game can't be won, though it may still not be winnable when this is true.
+    pub fn is_possibly_solvable(&self) -> bool {
+        let needed = self.sides.full_bits() & !self.used_bits;
+        let mut covered = 0;
+        for word in self.board_words().iter().filter(|w| self.validate_length(w).is_ok()) {
+            covered |= self.sides.word_bits(word);
+            if covered & needed == needed {
+                return true;
             }
-            None => None
         }
+        needed == 0
+    }
+
+    /// Gets the game for the current day.
+    #[cfg(feature = "fetch")]
+    pub fn today() -> Option<Self> {
+        game_data::get_data().ok().and_then(|game_data| Self::try_from(&game_data).ok())
     }
 
     /// Gets the game for yesterday.
+    #[cfg(feature = "fetch")]
     pub fn yesterday() -> Option<Self> {
-        match game_data::get_data() {
-            Some(game_data) => {
-                let sides = Sides::from_sides(&game_data.yesterdaysSides);
-                Some(Self::new(sides))
-            }
-            None => None
-        }
+        game_data::get_data().ok().and_then(|game_data| Self::from_yesterday_data(&game_data).ok())
+    }
+
+    /// Gets the game for the current day, giving the error if it couldn't be fetched.
+    #[cfg(feature = "fetch")]
+    pub fn try_today() -> Result<Self, DataError> {
+        Self::today_from(&NytSource::new()?)
+    }
+
+    /// Gets the game for yesterday, giving the error if it couldn't be fetched.
+    #[cfg(feature = "fetch")]
+    pub fn try_yesterday() -> Result<Self, DataError> {
+        Self::yesterday_from(&NytSource::new()?)
+    }
+
+    /// Gets the game for the current day from the given source.
+    pub fn today_from(source: &impl GameSource) -> Result<Self, DataError> {
+        Ok(Self::try_from(&source.fetch_today()?)?)
+    }
+
+    /// Gets the game for yesterday from the given source.
+    pub fn yesterday_from(source: &impl GameSource) -> Result<Self, DataError> {
+        Ok(Self::from_yesterday_data(&source.fetch_today()?)?)
+    }
+
+    fn from_yesterday_data(game_data: &GameData) -> Result<Self, SidesError> {
+        Sides::try_from(game_data.yesterdaysSides.clone()).map(Self::new)
     }
 
     pub fn sides(&self) -> &Sides {
         &self.sides
     }
 
+    /// Gets the board letters that haven't been used yet.
+    pub fn remaining_letters(&self) -> &HashSet<char> {
+        &self.remaining_letters
+    }
+
+    /// Gets the board letters that have been used.
+    pub fn used_letters(&self) -> &HashSet<char> {
+        &self.used_letters
+    }
+
+    /// Gets the words played so far, in order.
+    pub fn previous_words(&self) -> &[String] {
+        &self.previous_words
+    }
+
+    /// Gets the side of each letter in the word, in order. Letters that aren't on the board are skipped.
+    pub fn sides_used_by_word(&self, word: &str) -> Vec<Side> {
+        word.chars().filter_map(|letter| self.sides.get_side(letter)).collect()
+    }
+
+    /// Counts how many letters of the words played so far landed on each side. Every side is included, even if
+    /// none of its letters have been used.
+    pub fn side_usage(&self) -> HashMap<Side, usize> {
+        let mut usage = HashMap::from([Side::Top, Side::Right, Side::Bottom, Side::Left].map(|side| (side, 0)));
+        for side in self.previous_words.iter().flat_map(|word| self.sides_used_by_word(word)) {
+            *usage.entry(side).or_default() += 1;
+        }
+        usage
+    }
+
+    /// Gets the number of words the puzzle should be solved in, if known.
+    pub fn par(&self) -> Option<usize> {
+        self.par
+    }
+
+    pub fn set_par(&mut self, par: Option<usize>) {
+        self.par = par;
+    }
+
+    /// Gets the rules this game is played by.
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Gets the fewest letters a word must have to be played in this game.
+    pub fn min_word_len(&self) -> usize {
+        self.rules.min_word_len
+    }
+
+    /// Sets the fewest letters a word must have to be played in this game, which defaults to `MIN_WORD_LEN`.
+    /// Validation and solving both follow it.
+    pub fn set_min_word_len(&mut self, n: usize) {
+        self.rules.min_word_len = n;
+    }
+
+    /// Sets whether a word can be played more than once in this game, which it can by default.
+    /// When it can't, `check_dict_word` and `validate_word` reject a word that's already been played.
+    pub fn set_allow_reuse(&mut self, allow_reuse: bool) {
+        self.rules.allow_reuse = allow_reuse;
+    }
+
+    /// Checks if the given word hasn't been played yet in this game, whether or not reuse is allowed.
+    pub fn is_fresh_word(&self, word: &str) -> bool {
+        !self.previous_words.iter().any(|w| w == word)
+    }
+
     /// Checks if the given word is valid word, given that it is possible and is in the dictionary.
     pub fn check_dict_word(&self, word: &str) -> bool {
-        if !self.previous_words.is_empty()
-            && self.previous_words.last().unwrap().chars().last().unwrap() != word.chars().next().unwrap() {
-            return false;
+        self.validate_word(word).is_ok()
+    }
+
+    /// Gets the letter the next word has to start with, which is the last letter of the previous word,
+    /// or `None` if no word has been played yet.
+    pub fn next_start_letter(&self) -> Option<char> {
+        self.previous_words.last().and_then(|w| w.chars().last())
+    }
+
+    /// Checks if the given word can be played in the current game state, giving the reason if it can't.
+    /// The length is checked first, so a word that is too short, including an empty one, is always `TooShort`.
+    pub fn validate_word(&self, word: &str) -> Result<(), WordRejection> {
+        self.validate_length(word)?;
+
+        if let Some(expected) = self.next_start_letter() {
+            if !word.starts_with(expected) {
+                return Err(WordRejection::NotStartingWithLastLetter { expected });
+            }
+        }
+
+        self.validate_sides(word)?;
+
+        if !self.rules.allow_reuse && !self.is_fresh_word(word) {
+            return Err(WordRejection::AlreadyPlayed);
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the given word's length is allowed by the rules.
+    fn validate_length(&self, word: &str) -> Result<(), WordRejection> {
+        let len = word.chars().count();
+        if len < self.rules.min_word_len {
+            return Err(WordRejection::TooShort { min_len: self.rules.min_word_len });
+        }
+        if let Some(max_len) = self.rules.max_word_len.filter(|&max_len| len > max_len) {
+            return Err(WordRejection::TooLong { max_len });
         }
 
+        Ok(())
+    }
+
+    /// Checks if every letter of the given word is on the board, on a different side from the letter before it.
+    fn validate_sides(&self, word: &str) -> Result<(), WordRejection> {
         let mut prev_side: Option<Side> = None;
 
         for letter in word.chars() {
             if let Some(side) = self.sides.get_side(letter) {
-                if let Some(prev_side) = prev_side {
-                    if self.sides.get_letters(&prev_side).contains(&letter) {
-                        return false;
-                    }
+                if prev_side == Some(side) {
+                    return Err(WordRejection::ConsecutiveSameSide { letter, side });
                 }
                 prev_side = Some(side);
             } else {
-                return false;
+                return Err(WordRejection::LetterNotOnBoard(letter));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Counts how many dictionary words are valid in the current game state, and how many are rejected
+    /// for each reason.
+    pub fn validation_stats(&self) -> ValidationStats {
+        let mut stats = ValidationStats::default();
+
+        for word in self.dictionary() {
+            match self.validate_word(word) {
+                Ok(()) => stats.valid += 1,
+                Err(WordRejection::NotStartingWithLastLetter { .. }) => stats.not_starting_with_last_letter += 1,
+                Err(WordRejection::TooShort { .. }) => stats.too_short += 1,
+                Err(WordRejection::LetterNotOnBoard(_)) => stats.letter_not_on_board += 1,
+                Err(WordRejection::ConsecutiveSameSide { .. }) => stats.consecutive_same_side += 1,
+                Err(WordRejection::TooLong { .. }) => stats.too_long += 1,
+                Err(WordRejection::AlreadyPlayed) => stats.already_played += 1,
             }
         }
 
-        true
+        stats
     }
 
-    /// Gets all possible words for the given game state.
+    /// Gets all possible words for the given game state, including words already played. Letter Boxed allows
+    /// playing a word again, but it never covers a new letter; see `fresh_possible_words`.
+    /// The words are checked in parallel.
     pub fn possible_words(&self) -> HashSet<String> {
-        words::WORDS.iter()
-            .filter_map(|w| if self.check_dict_word(w) { Some(w.to_string()) } else { None })
+        self.board_words()
+            .par_iter()
+            .filter(|w| self.check_dict_word(w))
+            .cloned()
             .collect()
     }
 
-    /// Gets the number of new letters that a word will add.
-    fn count_new_letters(&self, word: &str) -> usize {
-        word.chars().collect::<HashSet<char>>()
-            .difference(&self.remaining_letters).count()
+    /// Gets the same words as `possible_words` one at a time, in dictionary order, checking each only when it's
+    /// asked for, so taking the first few or filtering by a prefix doesn't build the whole set. The words borrow
+    /// from the game's dictionary, which is `'static` for the bundled one but owned by the game otherwise, so
+    /// the iterator can't outlive the game.
+    pub fn iter_possible_words(&self) -> impl Iterator<Item = &str> + '_ {
+        self.dictionary()
+            .filter(|w| self.sides.has_only_board_letters(w) && self.check_dict_word(w))
     }
 
-    /// Plays the given word.
-    pub fn play_word(&mut self, word: &str) -> usize {
-        for letter in word.chars() {
-            self.used_letters.insert(letter);
-            self.remaining_letters.remove(&letter);
+    /// Gets the possible words for the given game state that haven't been played yet.
+    pub fn fresh_possible_words(&self) -> HashSet<String> {
+        let mut words = self.possible_words();
+        for word in &self.previous_words {
+            words.remove(word);
         }
-        self.previous_words.push(word.to_string());
+        words
+    }
 
-        self.count_new_letters(word)
+    /// Gets all words that can be played on the board starting with the given letter, or with any letter
+    /// if `start` is `None`. The previous word is ignored.
+    pub fn possible_words_from(&self, start: Option<char>) -> HashSet<String> {
+        self.board_words()
+            .iter()
+            .filter(|w| start.is_none_or(|start| w.starts_with(start)) && self.validate_length(w).is_ok())
+            .cloned()
+            .collect()
     }
 
-    /// Checks if the game has been won.
-    pub fn check_win(&self) -> bool {
-        self.used_letters.len() == 3 * 4
+    /// Gets all words that can be played on the board, keyed by their first letter. The previous word is ignored.
+    pub fn words_by_first_letter(&self) -> HashMap<char, Vec<String>> {
+        let mut words_by_letter: HashMap<char, Vec<String>> = HashMap::new();
+        for word in self.possible_words_from(None) {
+            words_by_letter.entry(word.chars().next().unwrap()).or_default().push(word);
+        }
+        words_by_letter
     }
 
-    /// Returns a string representation of the board.
+    /// Gets all words that can be played on the board, keyed by their length and sorted alphabetically.
+    /// The previous word is ignored.
+    pub fn words_by_length(&self) -> BTreeMap<usize, Vec<String>> {
+        let words_by_length = self.words_by_length.get_or_init(|| {
+            let mut words_by_length: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+            for word in self.board_words() {
+                words_by_length.entry(word.chars().count()).or_default().push(word.clone());
+            }
+            for words in words_by_length.values_mut() {
+                words.sort();
+            }
+            Arc::new(words_by_length)
+        });
+
+        // The cache has every length, since the rules can change.
+        words_by_length
+            .iter()
+            .filter(|&(&len, _)| len >= self.rules.min_word_len && self.rules.max_word_len.is_none_or(|max| len <= max))
+            .map(|(&len, words)| (len, words.clone()))
+            .collect()
+    }
+
+    /// Gets the longest word that can be played on the board, ignoring the previous word. Ties go to the word
+    /// that comes first alphabetically.
+    pub fn longest_word(&self) -> Option<String> {
+        self.possible_words_from(None)
+            .into_iter()
+            .max_by(|a, b| a.chars().count().cmp(&b.chars().count()).then_with(|| b.cmp(a)))
+    }
+
+    /// Gets every word that uses all the board letters, so it solves the game on its own from the start,
+    /// in alphabetical order. The previous word is ignored.
+    pub fn pangrams(&self) -> Vec<String> {
+        let mut pangrams = self.possible_words_from(None)
+            .into_iter()
+            .filter(|w| self.sides.slots(w) == self.sides.all_chars)
+            .collect::<Vec<String>>();
+        pangrams.sort();
+        pangrams
+    }
+
+    /// Gets up to `limit` words that can be played next, with the number of new letters each would cover.
+    /// Words covering the most letters come first, then shorter words, then alphabetical order.
+    pub fn best_next_words(&self, limit: usize) -> Vec<(String, usize)> {
+        let mut words = self.possible_words()
+            .into_iter()
+            .map(|w| {
+                let new_letters = self.new_letters_if_played(&w);
+                (w, new_letters)
+            })
+            .collect::<Vec<(String, usize)>>();
+
+        words.sort_by(|(a, a_new), (b, b_new)| b_new.cmp(a_new).then(a.len().cmp(&b.len())).then_with(|| a.cmp(b)));
+        words.truncate(limit);
+        words
+    }
+
+    /// Gets the single best word to play next, as ranked by `best_next_words`, or `None` if no word can be played.
+    pub fn hint(&self) -> Option<String> {
+        self.best_next_words(1).into_iter().next().map(|(word, _)| word)
+    }
+
+    /// Gets the number of new letters that a word will add, which are the board letters it uses that haven't
+    /// been used yet.
+    pub fn new_letters_if_played(&self, word: &str) -> usize {
+        self.sides.slots(word).difference(&self.used_letters).count()
+    }
+
+    /// Plays the given word, returning the number of new letters it covers.
+    /// The word is assumed to be valid; use `try_play_word` for unchecked input.
+    pub fn play_word(&mut self, word: &str) -> usize {
+        // Counted before the letters are marked as used.
+        let new_letters = self.new_letters_if_played(word);
+
+        self.mark_used(word);
+        self.previous_words.push(word.to_string());
+
+        new_letters
+    }
+
+    /// Plays the given word, returning the board letters it newly covers in the order they first appear.
+    /// The word is assumed to be valid, as with `play_word`.
+    pub fn play_word_detailed(&mut self, word: &str) -> Vec<char> {
+        let mut new_letters = Vec::new();
+        for letter in word.chars().filter_map(|letter| self.sides.slot(letter)) {
+            if self.remaining_letters.contains(&letter) && !new_letters.contains(&letter) {
+                new_letters.push(letter);
+            }
+        }
+
+        self.mark_used(word);
+        self.previous_words.push(word.to_string());
+
+        new_letters
+    }
+
+    /// Gets, for each board letter that has been used, the index in the played words of the word that first
+    /// covered it.
+    pub fn coverage_map(&self) -> HashMap<char, usize> {
+        let mut coverage = HashMap::new();
+        for (i, word) in self.previous_words.iter().enumerate() {
+            for letter in word.chars().filter_map(|letter| self.sides.slot(letter)) {
+                coverage.entry(letter).or_insert(i);
+            }
+        }
+        coverage
+    }
+
+    fn mark_used(&mut self, word: &str) {
+        for letter in word.chars().filter_map(|letter| self.sides.slot(letter)) {
+            self.used_letters.insert(letter);
+            self.remaining_letters.remove(&letter);
+        }
+        self.used_bits |= self.sides.word_bits(word);
+    }
+
+    /// Rebuilds the used and remaining letters from the words played so far.
+    /// Needed whenever the previous words are set directly rather than through `play_word`.
+    pub fn hydrate(&mut self) {
+        self.used_letters.clear();
+        self.remaining_letters = self.sides.all_chars.clone();
+        self.used_bits = 0;
+
+        for word in self.previous_words.clone() {
+            self.mark_used(&word);
+        }
+    }
+
+    /// Takes back the last word played, giving it, or `None` if no word has been played.
+    /// The letters are worked out again from the words that are left, since earlier words may use the same ones.
+    pub fn undo_word(&mut self) -> Option<String> {
+        let word = self.previous_words.pop()?;
+        self.hydrate();
+        Some(word)
+    }
+
+    /// Plays the given word if it is valid, returning the number of new letters it covers.
+    /// The game is left untouched if the word is rejected.
+    pub fn try_play_word(&mut self, word: &str) -> Result<usize, WordRejection> {
+        self.validate_word(word)?;
+        Ok(self.play_word(word))
+    }
+
+    /// Plays the given words in order, stopping at the first word that is rejected.
+    /// On failure, gives the index of the rejected word; the words before it stay played.
+    pub fn apply_words(&mut self, words: &[String]) -> Result<(), (usize, WordRejection)> {
+        for (i, word) in words.iter().enumerate() {
+            self.try_play_word(word).map_err(|rejection| (i, rejection))?;
+        }
+        Ok(())
+    }
+
+    /// Checks that the words played so far follow the rules, such as after loading a game from untrusted data,
+    /// giving the index of the first word that breaks them.
+    pub fn verify_history(&self) -> Result<(), (usize, WordRejection)> {
+        let mut replay = self.clone();
+        replay.previous_words.clear();
+        replay.hydrate();
+        replay.apply_words(&self.previous_words)
+    }
+
+    /// Checks that playing the given words in order, after the words played so far, follows the rules and wins
+    /// the game. The game itself is left untouched, so this can check a solution from somewhere else, like
+    /// `GameData::ourSolution`, before it's played.
+    pub fn validate_solution(&self, words: &[String]) -> Result<(), SolutionError> {
+        let mut game = self.clone();
+        game.apply_words(words).map_err(|(index, reason)| SolutionError::InvalidWord { index, reason })?;
+
+        if !game.check_win() {
+            return Err(SolutionError::NotWon { remaining: game.letters_remaining_count() });
+        }
+        Ok(())
+    }
+
+    /// Checks if the game has been won.
+    pub fn check_win(&self) -> bool {
+        self.used_bits == self.sides.full_bits()
+    }
+
+    /// Gets the number of board letters that haven't been used yet.
+    pub fn letters_remaining_count(&self) -> usize {
+        self.remaining_letters.len()
+    }
+
+    /// Gets the fraction of board letters that have been used, from 0 to 1.
+    pub fn progress(&self) -> f32 {
+        let total = self.sides.all_chars.len();
+        (total - self.remaining_letters.len()) as f32 / total as f32
+    }
+
+    /// Returns a string representation of the board.
     pub fn format_board(&self) -> String {
-        let mut output = String::new();
+        self.sides.format_board()
+    }
+
+    /// Returns a Markdown representation of the board in a code block, followed by a table of the given solutions.
+    pub fn to_markdown(&self, solutions: &[Vec<String>]) -> String {
+        let mut output = format!("```\n{}\n```\n\n", self.format_board());
 
-        output.push_str(&format!("  {} {} {}  \n", self.sides().top()[0], self.sides().top()[1], self.sides().top()[2]));
-        output.push_str(&format!("{}       {} \n", self.sides().left()[0], self.sides().right()[0]));
-        output.push_str(&format!("{}       {} \n", self.sides().left()[1], self.sides().right()[1]));
-        output.push_str(&format!("{}       {} \n", self.sides().left()[2], self.sides().right()[2]));
-        output.push_str(&format!("  {} {} {}  ", self.sides().bottom()[0], self.sides().bottom()[1], self.sides().bottom()[2]));
+        if solutions.is_empty() {
+            output.push_str("No solutions found.\n");
+            return output;
+        }
+
+        output.push_str("| # | Solution | Words | Letters |\n");
+        output.push_str("|---|----------|-------|---------|\n");
+        for (i, solution) in solutions.iter().enumerate() {
+            output.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                i + 1,
+                solution.join(" - "),
+                solution.len(),
+                solution.join("").len(),
+            ));
+        }
 
         output
     }
 }
 
+impl fmt::Display for Game {
+    /// Writes the board, followed by a line with the words played so far and the letters that are left.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut remaining = self.remaining_letters.iter().map(char::to_string).collect::<Vec<String>>();
+        remaining.sort();
+
+        let or_none = |list: String| if list.is_empty() { "none".to_string() } else { list };
+        write!(f, "{}\nWords: {} (remaining: {})", self.sides, or_none(self.previous_words.join(" - ")), or_none(remaining.join(" ")))
+    }
+}
+
+/// Fetches and solves the game for the current day.
+#[cfg(feature = "fetch")]
+pub fn solve_today() -> Result<SolveReport, DataError> {
+    Game::try_today().map(|game| Solver::solve_report(&game))
+}
+
+/// Fetches and solves the game for yesterday.
+#[cfg(feature = "fetch")]
+pub fn solve_yesterday() -> Result<SolveReport, DataError> {
+    Game::try_yesterday().map(|game| Solver::solve_report(&game))
+}
+
 #[derive(Debug)]
 pub struct Solver {}
 
 impl Solver {
-    /// Filters possible_words based on the new game state, given a possible_words list of a previous state.
-    fn quick_filter_possible_words(game: &Game, possible_words: &HashSet<String>) -> HashSet<String> {
-        match game.previous_words.last() {
+    /// Gets the words from `words_by_letter` that can follow the last word played in the given game, other than
+    /// words already played, since playing a word again never covers a new letter.
+    fn quick_filter_possible_words<'a>(game: &Game, words_by_letter: &'a HashMap<char, Vec<String>>) -> Vec<&'a String> {
+        match game.next_start_letter() {
             None => {
-                possible_words.to_owned()
+                words_by_letter.values().flatten().collect()
             }
-            Some(last_word) => {
-                possible_words
-                    .iter()
-                    .filter(|word| {
-                        word.chars().next().unwrap() == last_word.chars().last().unwrap()
-                    })
-                    .cloned()
-                    .collect()
+            Some(start) => {
+                words_by_letter
+                    .get(&start)
+                    .map(|words| words.iter().filter(|w| !game.previous_words.contains(w)).collect())
+                    .unwrap_or_default()
             }
         }
     }
@@ -233,30 +1226,389 @@ impl Solver {
     /// Solves the given game, stopping when there is one or more solution with at least `min_words` words.
     /// Ex. if min_words = 2, returns when it finds a solution with two or more words.
     pub fn solve_game(game: &Game, min_words: usize) -> Vec<Vec<String>> {
-        let possible_words = game.possible_words();
-        let solutions = Mutex::new(Vec::new());
+        Self::solve_with_config(game, min_words, &SolverConfig::default())
+    }
 
-        let mut games = vec![game.clone()];
+    /// Same as `solve_game`, using the given config.
+    /// If the config's deadline passes, the solutions found so far are given, which may be none; use
+    /// `solve_outcome` to tell whether that happened.
+    pub fn solve_with_config(game: &Game, min_words: usize, config: &SolverConfig) -> Vec<Vec<String>> {
+        Self::solve_outcome(game, min_words, config).solutions
+    }
+
+    /// Same as `solve_with_config`, also saying whether the search stopped because the config's deadline passed.
+    pub fn solve_outcome(game: &Game, min_words: usize, config: &SolverConfig) -> SolveOutcome {
+        Self::search(game, min_words, config, &|_| {})
+    }
+
+    /// Same as `solve_game`, calling `on_progress` after each word is searched.
+    pub fn solve_with_progress(game: &Game, min_words: usize, on_progress: impl Fn(SolveProgress) + Send + Sync) -> Vec<Vec<String>> {
+        Self::search(game, min_words, &SolverConfig::default(), &on_progress).solutions
+    }
+
+    fn search(game: &Game, min_words: usize, config: &SolverConfig, on_progress: &(dyn Fn(SolveProgress) + Send + Sync)) -> SolveOutcome {
+        let start = Instant::now();
+        if !game.is_possibly_solvable() {
+            return SolveOutcome { solutions: Vec::new(), timed_out: false };
+        }
+
+        let mut state = SolverState::with_config(game, config);
+        let mut solutions = Vec::new();
+        let mut timed_out = false;
+
+        while !state.is_exhausted() {
+            if solutions.iter().any(|s: &Vec<String>| s.len() >= min_words) {
+                break;
+            }
+            if config.max_solutions.is_some_and(|max| solutions.len() >= max) {
+                break;
+            }
+            if config.deadline.is_some_and(|deadline| start.elapsed() >= deadline) {
+                timed_out = true;
+                break;
+            }
+
+            solutions.extend(state.step());
+            on_progress(SolveProgress {
+                depth: state.depth,
+                frontier_len: state.frontier.len(),
+                solutions_found: solutions.len(),
+            });
+        }
+
+        debug_assert!(solutions.iter().all(|s| Self::is_winning_solution(game, s)));
+
+        Self::sort_solutions(&mut solutions, config.tie_break);
+        if let Some(max) = config.max_solutions {
+            solutions.truncate(max);
+        }
+        SolveOutcome { solutions, timed_out }
+    }
+
+    /// Checks if the given solution continues the given game and wins it, following the rules for the words
+    /// after the ones already played. Solutions include the words already played in the game they were found
+    /// from, which aren't checked again, since `play_word` doesn't check them in the first place.
+    fn is_winning_solution(game: &Game, solution: &[String]) -> bool {
+        let played = game.previous_words.len();
+        if !solution.starts_with(&game.previous_words) {
+            return false;
+        }
+
+        let mut replay = game.clone();
+        replay.apply_words(&solution[played..]).is_ok() && replay.check_win()
+    }
+
+    /// Sorts solutions by total character count, breaking ties with `tie_break` and then lexicographically
+    /// by the joined words, so the order doesn't depend on thread scheduling.
+    fn sort_solutions(solutions: &mut [Vec<String>], tie_break: TieBreak) {
+        solutions.sort_by(|a, b| {
+            let (a_joined, b_joined) = (a.join(""), b.join(""));
+            a_joined.len().cmp(&b_joined.len())
+                .then_with(|| tie_break.compare(a, b))
+                .then_with(|| a_joined.cmp(&b_joined))
+        });
+    }
+
+    /// Solves the given game, giving the most optimal solutions, sorted by total character count.
+    /// Gives no solutions if the game can't be won, or needs more than `MAX_DEPTH` (10) more words;
+    /// use `try_solve` to tell these apart, or `solve_with_config` to search to a different depth.
+    /// To compare several boards, solve them in a loop: filtering the dictionary for a board takes a millisecond
+    /// or two, so there's little setup worth sharing between them.
+    pub fn solve(game: &Game) -> Vec<Vec<String>> {
+        Self::solve_game(game, 0)
+    }
+
+    /// Same as `solve`, only searching solutions with at most `max_words` words, including the words already
+    /// played, such as the game's par. Gives no solutions if the game can't be won in that many words.
+    pub fn solve_within(game: &Game, max_words: usize) -> Vec<Vec<String>> {
+        let config = SolverConfig {
+            max_depth: Some(max_words.saturating_sub(game.previous_words.len())),
+            ..Default::default()
+        };
+        Self::solve_with_config(game, 0, &config)
+    }
+
+    /// Formats a solution for sharing: the board, the number of words and letters, and an emoji board with the
+    /// letters the solution used in green and the rest in white. The words themselves are left out, and no line
+    /// has trailing whitespace.
+    pub fn format_share(game: &Game, solution: &[String]) -> String {
+        let sides = game.sides();
+        let used = sides.word_bits(&solution.concat());
+        let square = |letter: &char| if sides.letter_bits[letter] & used != 0 { "🟩" } else { "⬜" };
+        let blank = "⬛";
+        let row = |letters: &[char]| format!("{}{}{}", blank, letters.iter().map(square).collect::<String>(), blank);
+
+        let words = if solution.len() == 1 { "word" } else { "words" };
+        let mut lines = vec![
+            format!("Letter Boxed {}", sides.to_shareable()),
+            format!("{} {}, {} letters", solution.len(), words, solution.iter().map(|w| w.chars().count()).sum::<usize>()),
+            row(sides.top()),
+        ];
+        for (left, right) in sides.left().iter().zip(sides.right()) {
+            lines.push(format!("{}{}{}", square(left), blank.repeat(sides.side_len()), square(right)));
+        }
+        lines.push(row(sides.bottom()));
+
+        lines.join("\n")
+    }
+
+    /// Counts the solutions `solve_within` would give for the same `max_words`, without collecting them.
+    pub fn count_solutions(game: &Game, max_words: usize) -> usize {
+        if !game.is_possibly_solvable() {
+            return 0;
+        }
+
+        let config = SolverConfig {
+            max_depth: Some(max_words.saturating_sub(game.previous_words.len())),
+            ..Default::default()
+        };
+        let mut state = SolverState::with_config(game, &config);
+        while !state.is_exhausted() {
+            let count = state.count_step();
+            if count > 0 {
+                return count;
+            }
+        }
+        0
+    }
+
+    /// Same as `solve`, giving the solutions in the given order.
+    pub fn solve_sorted(game: &Game, sort: SolutionSort) -> Vec<Vec<String>> {
+        let mut solutions = Self::solve(game);
+        solutions.sort_by(|a, b| sort.compare(a, b));
+        solutions
+    }
+
+    /// Solves the given game after playing each of the given openers first, giving the solutions for each opener
+    /// as with `solve`. Openers that can't be played get no solutions. The word index is built once and shared by
+    /// every opener.
+    pub fn solve_by_opener(game: &Game, openers: &[String]) -> HashMap<String, Vec<Vec<String>>> {
+        if !game.is_possibly_solvable() {
+            return openers.iter().map(|opener| (opener.clone(), Vec::new())).collect();
+        }
+
+        let base = SolverState::new(game);
+
+        openers
+            .iter()
+            .map(|opener| {
+                let mut played = game.clone();
+                if played.try_play_word(opener).is_err() {
+                    return (opener.clone(), Vec::new());
+                }
+                if played.check_win() {
+                    return (opener.clone(), vec![played.previous_words]);
+                }
+
+                let mut state = base.restart(&played);
+                let mut solutions = Vec::new();
+                while solutions.is_empty() && !state.is_exhausted() {
+                    solutions.extend(state.step());
+                }
+
+                Self::sort_solutions(&mut solutions, TieBreak::default());
+                (opener.clone(), solutions)
+            })
+            .collect()
+    }
+
+    /// Same as `solve`, but gives the reason if no solution was found.
+    pub fn try_solve(game: &Game) -> Result<Vec<Vec<String>>, NoSolution> {
+        if !game.is_possibly_solvable() {
+            return Err(NoSolution::Unsolvable);
+        }
+
+        let mut state = SolverState::new(game);
+        let mut solutions = Vec::new();
+
+        while solutions.is_empty() && !state.is_exhausted() {
+            solutions.extend(state.step());
+        }
+
+        if !solutions.is_empty() {
+            Self::sort_solutions(&mut solutions, TieBreak::default());
+            Ok(solutions)
+        } else if state.frontier.is_empty() {
+            Err(NoSolution::Unsolvable)
+        } else {
+            Err(NoSolution::DepthLimitReached(state.depth))
+        }
+    }
+
+    /// Solves the given game, giving the `k` best solutions, sorted by total character count.
+    /// Games are expanded in order of the shortest solution they could lead to, so the search stops as soon as
+    /// no unexpanded game could beat the `k`th best. The result is exact apart from solutions needing more
+    /// than `MAX_DEPTH` words.
+    pub fn solve_k(game: &Game, k: usize) -> Vec<Vec<String>> {
+        Self::solve_k_with_config(game, k, &SolverConfig::default())
+    }
+
+    /// Same as `solve_k`, using the config's tie-break to choose between, and order, solutions with the same
+    /// total character count, and its word filter and depth limit. `max_solutions` and `deadline` aren't used.
+    pub fn solve_k_with_config(game: &Game, k: usize, config: &SolverConfig) -> Vec<Vec<String>> {
+        if k == 0 || !game.is_possibly_solvable() {
+            return Vec::new();
+        }
+
+        let words_by_letter = game.words_by_first_letter();
+        let start_len = game.previous_words.len();
+        let max_depth = config.max_depth.unwrap_or(MAX_DEPTH);
+        let mut solutions: Vec<Vec<String>> = Vec::new();
+
+        let candidate = |game: Game, order: usize| {
+            Reverse(Candidate { bound: Self::min_total_letters(&game), tie_break: config.tie_break, order, game })
+        };
+        let mut queue = BinaryHeap::new();
+        let mut pushed = 0;
+        queue.push(candidate(game.clone(), pushed));
+
+        while let Some(Reverse(Candidate { bound, game, .. })) = queue.pop() {
+            if solutions.len() >= k && bound > solutions[k - 1].join("").len() {
+                break;
+            }
+
+            if game.check_win() {
+                solutions.push(game.previous_words);
+                continue;
+            }
+            if game.previous_words.len() - start_len >= max_depth {
+                continue;
+            }
+
+            for w in Self::quick_filter_possible_words(&game, &words_by_letter) {
+                if !config.keeps_word(w) {
+                    continue;
+                }
+                let mut next = game.clone();
+                next.play_word(w);
+                pushed += 1;
+                queue.push(candidate(next, pushed));
+            }
+        }
+
+        Self::sort_solutions(&mut solutions, config.tie_break);
+        solutions.truncate(k);
+        solutions
+    }
+
+    /// Finds a solution to the given game with the fewest total letters, using an A* search. Unlike `solve`, this
+    /// minimizes letters rather than words, so the solution may use more words than the shortest one. Gives
+    /// `None` if there is no solution within `MAX_DEPTH` more words.
+    pub fn solve_astar(game: &Game) -> Option<Vec<String>> {
+        Self::astar(game).0
+    }
+
+    /// Runs the search for `solve_astar`, also giving the number of games expanded.
+    ///
+    /// Games are expanded in order of the letters played so far plus a lower bound on the letters still needed.
+    /// A word that follows another starts with a letter that's already covered, so a word of length `n` covers
+    /// at most `n - 1` new letters, and at most one fewer than the most distinct letters in any playable word.
+    /// Covering the `r` remaining letters takes at least `k = ceil(r / that)` more words, which together have at
+    /// least `r + k` letters, and at least `k * min_word_len`. The bound never overestimates, so the first
+    /// winning game expanded has the fewest letters.
+    fn astar(game: &Game) -> (Option<Vec<String>>, usize) {
+        if !game.is_possibly_solvable() {
+            return (None, 0);
+        }
+
+        let words_by_letter = game.words_by_first_letter();
+        let max_new_letters = words_by_letter
+            .values()
+            .flatten()
+            .map(|w| w.chars().collect::<HashSet<char>>().len().saturating_sub(1))
+            .max()
+            .unwrap_or(0);
+
+        let lower_bound = |game: &Game| -> Option<usize> {
+            let played = game.previous_words.iter().map(|w| w.len()).sum::<usize>();
+            let remaining = game.remaining_letters.len();
+            if remaining == 0 || game.previous_words.is_empty() {
+                return Some(played + remaining);
+            }
+            if max_new_letters == 0 {
+                return None;
+            }
 
-        for _ in 0..10 {
-            if let Ok(solutions) = solutions.lock() {
-                if solutions.iter().any(|s: &Vec<String>| s.len() >= min_words) {
-                    break;
+            let words = remaining.div_ceil(max_new_letters);
+            Some(played + (remaining + words).max(words * game.rules.min_word_len))
+        };
+
+        let start_len = game.previous_words.len();
+        let mut expanded = 0;
+
+        // Ordered by the bound, then by the fewest letters left, then by insertion order so the search is
+        // deterministic.
+        let mut queue = BinaryHeap::new();
+        let mut games = vec![Some(game.clone())];
+        if let Some(bound) = lower_bound(game) {
+            queue.push(Reverse((bound, game.remaining_letters.len(), 0)));
+        }
+
+        while let Some(Reverse((_, _, i))) = queue.pop() {
+            let game = games[i].take().unwrap();
+            if game.check_win() {
+                return (Some(game.previous_words), expanded);
+            }
+            if game.previous_words.len() - start_len >= MAX_DEPTH {
+                continue;
+            }
+
+            expanded += 1;
+            for w in Self::quick_filter_possible_words(&game, &words_by_letter) {
+                let mut next = game.clone();
+                next.play_word(w);
+                if let Some(bound) = lower_bound(&next) {
+                    queue.push(Reverse((bound, next.remaining_letters.len(), games.len())));
+                    games.push(Some(next));
                 }
             }
+        }
+
+        (None, expanded)
+    }
+
+    /// Solves the given game, giving every solution with the fewest total letters, sorted as with `solve`. Unlike
+    /// `solve`, this keeps searching past the fewest words, since a solution with more words may have fewer
+    /// letters. The best total found so far is shared between threads, and games that can't get below it are
+    /// dropped, which keeps the search small once a good solution turns up.
+    pub fn solve_optimal(game: &Game) -> Vec<Vec<String>> {
+        Self::optimal_search(game, true).0
+    }
+
+    /// Runs the search for `solve_optimal`, also giving the number of games expanded. If `prune` isn't set, every
+    /// game is expanded, which gives the same solutions much more slowly.
+    fn optimal_search(game: &Game, prune: bool) -> (Vec<Vec<String>>, usize) {
+        if !game.is_possibly_solvable() {
+            return (Vec::new(), 0);
+        }
+
+        let words_by_letter = game.words_by_first_letter();
+        let best = AtomicUsize::new(usize::MAX);
+        let solutions = Mutex::new(Vec::new());
+        let mut expanded = 0;
+
+        let mut games = vec![game.clone()];
+        for _ in 0..MAX_DEPTH {
+            if games.is_empty() {
+                break;
+            }
+            expanded += games.len();
 
             games = games.par_iter()
                 .flat_map(|game| {
-                    Self::quick_filter_possible_words(game, &possible_words)
-                        .iter()
+                    Self::quick_filter_possible_words(game, &words_by_letter)
+                        .into_iter()
                         .filter_map(|w| {
                             let mut game = game.clone();
                             game.play_word(w);
                             if game.check_win() {
+                                best.fetch_min(game.previous_words.iter().map(|w| w.len()).sum(), AtomicOrdering::Relaxed);
                                 if let Ok(mut solutions) = solutions.lock() {
                                     solutions.push(game.previous_words);
                                 }
                                 None
+                            } else if prune && Self::min_total_letters(&game) > best.load(AtomicOrdering::Relaxed) {
+                                None
                             } else {
                                 Some(game)
                             }
@@ -264,42 +1616,2070 @@ impl Solver {
                         .collect::<Vec<Game>>()
                 })
                 .collect::<Vec<Game>>();
+
+            // Games kept before a better solution was found in the same step can be dropped now.
+            if prune {
+                let best = best.load(AtomicOrdering::Relaxed);
+                games.retain(|game| Self::min_total_letters(game) <= best);
+            }
         }
 
-        let mut solutions = solutions.lock().unwrap().to_owned();
-        solutions.sort_by_key(|s| s.join("").len());
-        solutions
+        let best = best.into_inner();
+        let mut solutions = solutions.into_inner().unwrap();
+        solutions.retain(|s| s.iter().map(|w| w.len()).sum::<usize>() == best);
+        Self::sort_solutions(&mut solutions, TieBreak::default());
+        (solutions, expanded)
     }
 
-    /// Solves the given game, giving the most optimal solutions, sorted by total character count.
-    pub fn solve(game: &Game) -> Vec<Vec<String>> {
-        Self::solve_game(game, 0)
+    /// Gets a lower bound for the total character count of any solution continuing the given game.
+    /// If letters remain, another word is needed to cover them, and it has at least one more letter
+    /// than that since it starts with the letter the previous word ended with.
+    fn min_total_letters(game: &Game) -> usize {
+        let played = game.previous_words.iter().map(|w| w.len()).sum::<usize>();
+        match game.remaining_letters.len() {
+            0 => played,
+            remaining => played + (remaining + 1).max(game.rules.min_word_len),
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Ranks the words that can be played next by how promising they are, best first, giving at most
+    /// `MAX_OPENERS` words with their scores. A word scores 10 points for each new letter it covers, plus a
+    /// point for each word that could follow it and cover more letters, up to 10, so a word that covers
+    /// another letter is worth about as much as a word with ten more follow-ups. Ties go to shorter words,
+    /// then alphabetical order.
+    pub fn rank_openers(game: &Game) -> Vec<(String, usize)> {
+        let words_by_letter = game.words_by_first_letter();
 
-    fn setup_game() -> Game {
-        let sides = Sides::from_str("DKI", "JTA", "CLV", "ERO");
-        Game::new(sides)
+        let mut openers = Self::quick_filter_possible_words(game, &words_by_letter)
+            .into_par_iter()
+            .map(|w| {
+                let mut next = game.clone();
+                let new_letters = next.play_word(w);
+                let follow_ups = Self::quick_filter_possible_words(&next, &words_by_letter)
+                    .into_iter()
+                    .filter(|follow_up| next.new_letters_if_played(follow_up) > 0)
+                    .count();
+
+                (w.clone(), new_letters * 10 + follow_ups.min(10))
+            })
+            .collect::<Vec<(String, usize)>>();
+
+        openers.sort_by(|(a, a_score), (b, b_score)| {
+            b_score.cmp(a_score).then(a.len().cmp(&b.len())).then(a.cmp(b))
+        });
+        openers.truncate(MAX_OPENERS);
+        openers
     }
 
-    #[test]
-    fn test_mark_word() {
-        let mut game = setup_game();
-        assert_eq!(game.play_word("OKRA"), 4);
-        assert_eq!(game.play_word("OKRA"), 0);
-        assert_eq!(game.play_word("ADJECTIVAL"), 8);
+    /// Solves the given game, rating each solution against the game's par.
+    /// The rarest word of each solution is picked by length; see `rarest_word`.
+    pub fn solve_report(game: &Game) -> SolveReport {
+        Self::report(game, None)
     }
 
-    #[test]
-    fn test_solve() {
-        let game = setup_game();
-        let solutions = Solver::solve(&game);
+    /// Same as `solve_report`, picking the rarest word of each solution using the given word frequencies.
+    pub fn solve_report_with_frequencies(game: &Game, frequencies: &HashMap<String, f64>) -> SolveReport {
+        Self::report(game, Some(frequencies))
+    }
 
-        assert!(!solutions.is_empty());
-        assert!(solutions.contains(&vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]));
+    /// Same as `solve_report`, giving the solutions in the given order.
+    pub fn solve_report_sorted(game: &Game, sort: SolutionSort) -> SolveReport {
+        Self::rate(game, Self::solve_sorted(game, sort), None)
+    }
+
+    /// Rates the given solutions to the game in the same way as `solve_report`, keeping their order. Useful for
+    /// solutions found some other way, like with a `Solve` strategy.
+    pub fn rate_solutions(game: &Game, solutions: Vec<Vec<String>>) -> SolveReport {
+        Self::rate(game, solutions, None)
+    }
+
+    fn report(game: &Game, frequencies: Option<&HashMap<String, f64>>) -> SolveReport {
+        Self::rate(game, Self::solve(game), frequencies)
+    }
+
+    fn rate(game: &Game, solutions: Vec<Vec<String>>, frequencies: Option<&HashMap<String, f64>>) -> SolveReport {
+        let solutions = solutions
+            .into_iter()
+            .map(|words| RatedSolution {
+                total_letters: words.join("").len(),
+                word_count: words.len(),
+                beats_par: game.par.map(|par| words.len() < par),
+                rarest_word: Self::rarest_word(&words, frequencies).map(str::to_string),
+                words,
+            })
+            .collect();
+
+        SolveReport {
+            board: game.sides.to_strings(),
+            par: game.par,
+            solutions,
+        }
+    }
+
+    /// Gets the least common word in the given solution, according to `frequencies`. Words missing from
+    /// `frequencies` count as never used. Without frequencies, longer words are assumed to be rarer.
+    /// Ties go to the earlier word.
+    pub fn rarest_word<'a>(solution: &'a [String], frequencies: Option<&HashMap<String, f64>>) -> Option<&'a str> {
+        let mut rarest: Option<&String> = None;
+
+        for word in solution {
+            let is_rarer = rarest.is_none_or(|rarest| match frequencies {
+                Some(frequencies) => {
+                    let frequency = |w: &String| frequencies.get(w).copied().unwrap_or(0.0);
+                    frequency(word) < frequency(rarest)
+                }
+                None => word.len() > rarest.len(),
+            });
+
+            if is_rarer {
+                rarest = Some(word);
+            }
+        }
+
+        rarest.map(String::as_str)
+    }
+
+    /// Solves the given game using each board letter only once, with the letter shared by two chained words
+    /// counting once. Every solution has exactly `letters + words - 1` characters, where `letters` is the number
+    /// of board letters, so they're sorted lexicographically.
+    pub fn solve_no_letter_reuse(game: &Game) -> Vec<Vec<String>> {
+        if !game.is_possibly_solvable() {
+            return Vec::new();
+        }
+
+        let words_by_letter = game.words_by_first_letter();
+
+        let mut endings = HashMap::new();
+        let mut solutions = Vec::new();
+
+        for w in Self::quick_filter_possible_words(game, &words_by_letter) {
+            if !Self::uses_only_new_letters(game, w) {
+                continue;
+            }
+
+            let mut game = game.clone();
+            game.play_word(w);
+            for ending in Self::no_letter_reuse_endings(&game, &words_by_letter, &mut endings) {
+                solutions.push(game.previous_words.iter().chain(&ending).cloned().collect());
+            }
+        }
+
+        Self::sort_solutions(&mut solutions, TieBreak::default());
+        solutions
+    }
+
+    /// Gets every list of words that wins the given game without reusing letters. An already won game has
+    /// one empty ending. `endings` caches the result for each (used letters, last letter) state, since
+    /// that's all the result depends on.
+    fn no_letter_reuse_endings(
+        game: &Game,
+        words_by_letter: &HashMap<char, Vec<String>>,
+        endings: &mut HashMap<(Vec<char>, char), Vec<Vec<String>>>,
+    ) -> Vec<Vec<String>> {
+        if game.check_win() {
+            return vec![Vec::new()];
+        }
+
+        let mut used_letters = game.used_letters.iter().copied().collect::<Vec<char>>();
+        used_letters.sort();
+        let state = (used_letters, game.next_start_letter().unwrap());
+        if let Some(cached) = endings.get(&state) {
+            return cached.clone();
+        }
+
+        let mut result = Vec::new();
+        for w in Self::quick_filter_possible_words(game, words_by_letter) {
+            if !Self::uses_only_new_letters(game, w) {
+                continue;
+            }
+
+            let mut next = game.clone();
+            next.play_word(w);
+            for ending in Self::no_letter_reuse_endings(&next, words_by_letter, endings) {
+                result.push(std::iter::once(w.clone()).chain(ending).collect());
+            }
+        }
+
+        endings.insert(state, result.clone());
+        result
+    }
+
+    /// Checks if every letter of the word is unused, other than the first letter if it chains from the previous word.
+    fn uses_only_new_letters(game: &Game, word: &str) -> bool {
+        let skip = if game.previous_words.is_empty() { 0 } else { 1 };
+        let mut seen = HashSet::new();
+        word.chars().skip(skip).all(|letter| !game.used_letters.contains(&letter) && seen.insert(letter))
+    }
+
+    /// Counts how many times each board letter appears in the words of the solutions `solve` finds, counting
+    /// every occurrence. Letters that appear in no solution are left out, so an unsolvable game gives an empty map.
+    pub fn letter_frequency_in_solutions(game: &Game) -> HashMap<char, usize> {
+        let mut frequencies = HashMap::new();
+        for letter in Self::solve(game).iter().flatten().flat_map(|w| w.chars()) {
+            *frequencies.entry(letter).or_insert(0) += 1;
+        }
+        frequencies
+    }
+
+    /// Counts the solutions to the given game by how many more words they use, searching every game up to
+    /// `MAX_DEPTH` more words. A solution isn't continued once it wins. Gives an empty map if the game can't be
+    /// won. Searching that deep takes far too long with the bundled dictionary, so this is meant for games with
+    /// a small dictionary; use `length_distribution_within` to stop sooner.
+    pub fn length_distribution(game: &Game) -> BTreeMap<usize, usize> {
+        Self::length_distribution_within(game, MAX_DEPTH)
+    }
+
+    /// Same as `length_distribution`, only counting solutions with at most `max_words` more words.
+    pub fn length_distribution_within(game: &Game, max_words: usize) -> BTreeMap<usize, usize> {
+        if !game.is_possibly_solvable() {
+            return BTreeMap::new();
+        }
+
+        let mut state = SolverState::new(game);
+        let mut distribution = BTreeMap::new();
+
+        while !state.is_exhausted() && state.depth < max_words {
+            let solutions = state.step();
+            if !solutions.is_empty() {
+                distribution.insert(state.depth, solutions.len());
+            }
+        }
+
+        distribution
+    }
+
+    /// Checks if the given game can be won with two more words. Each word that can be played next is paired with
+    /// the words starting with its last letter, stopping at the first pair that covers every remaining letter.
+    pub fn is_two_word_solvable(game: &Game) -> bool {
+        if !game.is_possibly_solvable() {
+            return false;
+        }
+
+        let words_by_letter = game.words_by_first_letter();
+
+        Self::quick_filter_possible_words(game, &words_by_letter)
+            .par_iter()
+            .any(|first| {
+                let remaining = game.remaining_letters
+                    .difference(&game.sides.slots(first))
+                    .copied()
+                    .collect::<HashSet<char>>();
+
+                words_by_letter
+                    .get(&first.chars().last().unwrap())
+                    .is_some_and(|words| words.iter().any(|second| remaining.is_subset(&game.sides.slots(second))))
+            })
+    }
+
+    /// Gets the fewest words any solution to the given game uses, counting words already played.
+    /// Gives `None` if there is no solution within `MAX_DEPTH` more words.
+    pub fn min_word_count(game: &Game) -> Option<usize> {
+        if !game.is_possibly_solvable() {
+            return None;
+        }
+
+        let mut state = SolverState::new(game);
+        while !state.is_exhausted() {
+            if state.count_step() > 0 {
+                return Some(game.previous_words.len() + state.depth);
+            }
+        }
+
+        None
+    }
+
+    /// Describes how the given solution plays out, one line per word, giving the letters each word covers,
+    /// then how it compares to par. Stops at the first word that can't be played, giving the reason.
+    pub fn explain(game: &Game, solution: &[String]) -> String {
+        let mut game = game.clone();
+        let mut lines = Vec::new();
+
+        for word in solution {
+            let chained = game.next_start_letter();
+            if let Err(rejection) = game.validate_word(word) {
+                lines.push(format!("Can't play {}: {}.", word, rejection));
+                return lines.join("\n");
+            }
+
+            let new_letters = game.play_word_detailed(word);
+            let mut line = format!("Play {}", word);
+            if let Some(letter) = chained {
+                line.push_str(&format!(" starting with {}", letter));
+            }
+            if new_letters.is_empty() {
+                line.push_str(" — covers no new letters");
+            } else {
+                let covered = new_letters.iter().map(char::to_string).collect::<Vec<String>>().join(",");
+                line.push_str(&format!(" — covers {}", covered));
+            }
+            if game.check_win() {
+                line.push_str("; board complete.");
+            } else {
+                line.push_str(&format!("; ends on {}.", word.chars().last().unwrap()));
+            }
+            lines.push(line);
+        }
+
+        let words = game.previous_words.len();
+        if !game.check_win() {
+            lines.push(format!("Not solved: {} letters remaining.", game.letters_remaining_count()));
+        } else if let Some(par) = game.par {
+            let comparison = match words.cmp(&par) {
+                Ordering::Less => format!("{} under par ({})", par - words, par),
+                Ordering::Equal => format!("matching par ({})", par),
+                Ordering::Greater => format!("{} over par ({})", words - par, par),
+            };
+            lines.push(format!("Solved in {} words, {}.", words, comparison));
+        } else {
+            lines.push(format!("Solved in {} words.", words));
+        }
+
+        lines.join("\n")
+    }
+
+    /// Rates how hard the given game is, based on how many two-word solutions it has, how short its
+    /// shortest solution is, and how many rare letters are on the board.
+    pub fn difficulty(game: &Game) -> Difficulty {
+        let solutions = Self::solve_game(game, 2);
+        let two_word_solutions = solutions.iter().filter(|s| s.len() == 2).count();
+        let shortest_solution = solutions.first().map(|s| s.join("").len());
+        let rare_letters = game.sides.all_chars.iter().filter(|c| RARE_LETTERS.contains(c)).count();
+
+        let level = if two_word_solutions == 0 || rare_letters >= 5 {
+            DifficultyLevel::Hard
+        } else if two_word_solutions >= 25 && rare_letters <= 2 && shortest_solution.is_some_and(|len| len <= 14) {
+            DifficultyLevel::Easy
+        } else if two_word_solutions < 5 || rare_letters >= 4 {
+            DifficultyLevel::Hard
+        } else {
+            DifficultyLevel::Medium
+        };
+
+        Difficulty { level, two_word_solutions, shortest_solution, rare_letters }
+    }
+}
+
+/// A breadth-first search over a game that can be advanced one word at a time.
+#[derive(Debug)]
+pub struct SolverState {
+    words_by_letter: HashMap<char, Vec<String>>,
+    /// The bits of each word in `words_by_letter`, in the same order, as numbered by `Sides::word_bits`.
+    word_bits: HashMap<char, Vec<u32>>,
+    /// The bits of every board letter.
+    full_bits: u32,
+    /// The bits of the letters that can still be covered after a word ending with each letter, if `prune` is set.
+    coverable_letters: Option<HashMap<char, u32>>,
+    frontier: Vec<Game>,
+    depth: usize,
+    /// The most steps the search takes.
+    max_depth: usize,
+    nodes_expanded: usize,
+}
+
+impl SolverState {
+    pub fn new(game: &Game) -> Self {
+        Self::with_config(game, &SolverConfig::default())
+    }
+
+    /// Only searches words allowed by the given config.
+    pub fn with_config(game: &Game, config: &SolverConfig) -> Self {
+        Self::build(game, config, true)
+    }
+
+    /// If `prune` is set, games that need a letter no chain of words from their last letter can cover are
+    /// dropped from the frontier, since they can never be won. This doesn't change which solutions are found.
+    fn build(game: &Game, config: &SolverConfig, prune: bool) -> Self {
+        let mut words_by_letter = game.words_by_first_letter();
+        for words in words_by_letter.values_mut() {
+            words.retain(|w| config.keeps_word(w));
+        }
+        // Words only give the letters they're spelled with, not the wildcard they're played on, so the
+        // coverable letters would never include it.
+        #[cfg(feature = "wildcards")]
+        let prune = prune && !game.sides.all_chars.contains(&WILDCARD);
+        let coverable_letters = prune.then(|| {
+            Self::coverable_letters(&words_by_letter)
+                .into_iter()
+                .map(|(start, letters)| (start, game.sides.word_bits(&letters.into_iter().collect::<String>())))
+                .collect()
+        });
+        let word_bits = words_by_letter
+            .iter()
+            .map(|(&start, words)| (start, words.iter().map(|w| game.sides.word_bits(w)).collect()))
+            .collect();
+
+        Self {
+            words_by_letter,
+            word_bits,
+            full_bits: game.sides.full_bits(),
+            coverable_letters,
+            frontier: vec![game.clone()],
+            depth: 0,
+            max_depth: config.max_depth.unwrap_or(MAX_DEPTH),
+            nodes_expanded: 0,
+        }
+    }
+
+    /// Starts a new search from the given game, using the same words and pruning. The game must be on the same
+    /// board, with the same rules, as the one this search was built from.
+    fn restart(&self, game: &Game) -> Self {
+        Self {
+            words_by_letter: self.words_by_letter.clone(),
+            word_bits: self.word_bits.clone(),
+            full_bits: self.full_bits,
+            coverable_letters: self.coverable_letters.clone(),
+            frontier: vec![game.clone()],
+            depth: 0,
+            max_depth: self.max_depth,
+            nodes_expanded: 0,
+        }
+    }
+
+    /// Gets, for each letter, every letter used by a word in any chain of words starting with that letter.
+    fn coverable_letters(words_by_letter: &HashMap<char, Vec<String>>) -> HashMap<char, HashSet<char>> {
+        let letters_by_start = words_by_letter
+            .iter()
+            .map(|(&start, words)| (start, words.iter().flat_map(|w| w.chars()).collect::<HashSet<char>>()))
+            .collect::<HashMap<char, HashSet<char>>>();
+
+        let ends_by_start = words_by_letter
+            .iter()
+            .map(|(&start, words)| (start, words.iter().map(|w| w.chars().last().unwrap()).collect::<HashSet<char>>()))
+            .collect::<HashMap<char, HashSet<char>>>();
+
+        let mut coverable_letters = HashMap::new();
+        for &start in words_by_letter.keys().chain(ends_by_start.values().flatten()) {
+            let mut reached = HashSet::from([start]);
+            let mut queue = vec![start];
+            while let Some(letter) = queue.pop() {
+                for &end in ends_by_start.get(&letter).into_iter().flatten() {
+                    if reached.insert(end) {
+                        queue.push(end);
+                    }
+                }
+            }
+
+            let letters = reached
+                .iter()
+                .filter_map(|letter| letters_by_start.get(letter))
+                .flatten()
+                .copied()
+                .collect();
+            coverable_letters.insert(start, letters);
+        }
+
+        coverable_letters
+    }
+
+    /// Checks if every letter not in `used_bits` can be covered by some chain of words starting with `start`.
+    fn can_finish(&self, start: char, used_bits: u32) -> bool {
+        let Some(coverable_letters) = &self.coverable_letters else {
+            return true;
+        };
+
+        coverable_letters
+            .get(&start)
+            .is_some_and(|coverable| self.full_bits & !used_bits & !coverable == 0)
+    }
+
+    /// Gets the words that can be played next in the given game, with their bits, in the same way as
+    /// `Solver::quick_filter_possible_words`.
+    fn next_words<'a>(&'a self, game: &'a Game) -> Vec<(&'a String, u32)> {
+        let with_bits = |start: &char| {
+            self.words_by_letter[start].iter().zip(self.word_bits[start].iter().copied())
+        };
+
+        match game.next_start_letter() {
+            None => self.words_by_letter.keys().flat_map(with_bits).collect(),
+            Some(start) if self.words_by_letter.contains_key(&start) => {
+                with_bits(&start).filter(|(w, _)| !game.previous_words.contains(w)).collect()
+            }
+            Some(_) => Vec::new(),
+        }
+    }
+
+    /// Plays every possible next word on every unfinished game in the frontier, giving the solutions found.
+    /// The unfinished games that can still be won become the new frontier.
+    pub fn step(&mut self) -> Vec<Vec<String>> {
+        let solutions = Mutex::new(Vec::new());
+        self.expand(|game, w| {
+            if let Ok(mut solutions) = solutions.lock() {
+                let mut solution = game.previous_words.clone();
+                solution.push(w.clone());
+                solutions.push(solution);
+            }
+        });
+        solutions.into_inner().unwrap()
+    }
+
+    /// Same as `step`, only counting the solutions found instead of collecting them.
+    pub fn count_step(&mut self) -> usize {
+        let count = AtomicUsize::new(0);
+        self.expand(|_, _| {
+            count.fetch_add(1, AtomicOrdering::Relaxed);
+        });
+        count.into_inner()
+    }
+
+    /// Expands the frontier, calling `on_win` with the game and the word for every solution found.
+    fn expand(&mut self, on_win: impl Fn(&Game, &String) + Sync) {
+        if self.is_exhausted() {
+            return;
+        }
+
+        self.nodes_expanded += self.frontier.len();
+
+        // Wins and pruning are checked on the letter bits, so a game is only cloned if it goes in the frontier.
+        self.frontier = self.frontier.par_iter()
+            .flat_map(|game| {
+                self.next_words(game)
+                    .into_iter()
+                    .filter_map(|(w, bits)| {
+                        let used_bits = game.used_bits | bits;
+                        if used_bits == self.full_bits {
+                            on_win(game, w);
+                            None
+                        } else if self.can_finish(w.chars().last().unwrap(), used_bits) {
+                            let mut game = game.clone();
+                            game.play_word(w);
+                            Some(game)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Vec<Game>>()
+            })
+            .collect::<Vec<Game>>();
+        self.depth += 1;
+    }
+
+    /// Checks if there's nothing left to search, either because the frontier is empty or the most steps were taken.
+    pub fn is_exhausted(&self) -> bool {
+        self.frontier.is_empty() || self.depth >= self.max_depth
+    }
+
+    /// Gets the unfinished games that the next step will expand.
+    pub fn frontier(&self) -> &[Game] {
+        &self.frontier
+    }
+
+    /// Gets the number of steps taken so far.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Gets the number of games that have been expanded so far.
+    pub fn nodes_expanded(&self) -> usize {
+        self.nodes_expanded
+    }
+}
+
+/// The most words `Solver::rank_openers` gives.
+const MAX_OPENERS: usize = 20;
+
+/// The most words the solver will play on top of a game before giving up.
+const MAX_DEPTH: usize = 10;
+
+/// Options for how the solver searches and orders its results.
+#[derive(Clone, Debug, Default)]
+pub struct SolverConfig {
+    /// How solutions with the same total character count are ordered.
+    pub tie_break: TieBreak,
+    /// How common each word is, as used by `min_frequency`.
+    /// This is separate from the frequencies given to `Solver::solve_report_with_frequencies`, which only
+    /// pick the rarest word of each solution rather than changing which solutions are found.
+    pub frequencies: Option<HashMap<String, f64>>,
+    /// If set, words less common than this are never played. Words missing from `frequencies`, or every word
+    /// if `frequencies` isn't set, count as less common.
+    pub min_frequency: Option<f64>,
+    /// If set, the search stops once it has found this many solutions, and only the best this many are given.
+    /// Solutions with the same number of words are all found together, so they're still ranked before the rest
+    /// are dropped.
+    pub max_solutions: Option<usize>,
+    /// The most words to play on top of the game, or `None` for `MAX_DEPTH` (10).
+    pub max_depth: Option<usize>,
+    /// If set, no more words are searched once this long has passed. It's checked before each word, so the
+    /// search can run over by however long the last word takes.
+    pub deadline: Option<Duration>,
+}
+
+impl SolverConfig {
+    /// Checks if the solver may play the given word.
+    pub fn keeps_word(&self, word: &str) -> bool {
+        let Some(min_frequency) = self.min_frequency else {
+            return true;
+        };
+
+        self.frequencies
+            .as_ref()
+            .and_then(|frequencies| frequencies.get(word))
+            .is_some_and(|&frequency| frequency >= min_frequency)
+    }
+}
+
+/// A game waiting to be expanded by `Solver::solve_k_with_config`. Candidates are ordered by the lower bound on
+/// their total character count, then by the tie-break on the words played so far, then by when they were added,
+/// so the search is deterministic.
+struct Candidate {
+    bound: usize,
+    tie_break: TieBreak,
+    order: usize,
+    game: Game,
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bound
+            .cmp(&other.bound)
+            .then_with(|| self.tie_break.compare(&self.game.previous_words, &other.game.previous_words))
+            .then_with(|| self.order.cmp(&other.order))
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+/// How to order solutions with the same total character count.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum TieBreak {
+    /// Compare the joined words lexicographically.
+    #[default]
+    Lexicographic,
+    /// Compare the words one at a time lexicographically.
+    LexByWords,
+    /// Prefer solutions with fewer words.
+    FewestWordsFirst,
+    /// Compare the first words alphabetically.
+    AlphabeticalFirstWord,
+    /// Compare with the given function.
+    Custom(fn(&[String], &[String]) -> Ordering),
+}
+
+impl TieBreak {
+    fn compare(&self, a: &[String], b: &[String]) -> Ordering {
+        match self {
+            Self::Lexicographic => Ordering::Equal,
+            Self::LexByWords => a.cmp(b),
+            Self::FewestWordsFirst => a.len().cmp(&b.len()),
+            Self::AlphabeticalFirstWord => a.first().cmp(&b.first()),
+            Self::Custom(compare) => compare(a, b),
+        }
+    }
+}
+
+/// How `Solver::solve_sorted` orders solutions. Each order falls back to the next criteria it doesn't
+/// already use, ending with comparing the words one at a time, so the order is always the same.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum SolutionSort {
+    /// Fewest total characters first, then the joined words lexicographically, like `Solver::solve`.
+    #[default]
+    TotalChars,
+    /// Fewest words first, then fewest total characters.
+    WordCount,
+    /// The words compared one at a time lexicographically.
+    Lexicographic,
+}
+
+impl SolutionSort {
+    /// Compares two solutions in this order.
+    pub fn compare(&self, a: &[String], b: &[String]) -> Ordering {
+        let total_chars = |s: &[String]| s.iter().map(String::len).sum::<usize>();
+        let order = match self {
+            Self::TotalChars => total_chars(a).cmp(&total_chars(b)).then_with(|| a.concat().cmp(&b.concat())),
+            Self::WordCount => a.len().cmp(&b.len()).then_with(|| total_chars(a).cmp(&total_chars(b))),
+            Self::Lexicographic => Ordering::Equal,
+        };
+        order.then_with(|| a.cmp(b))
+    }
+}
+
+/// The reason the solver found no solutions.
+#[derive(Debug, PartialEq, Eq)]
+pub enum NoSolution {
+    /// Every game was searched and none can be won.
+    Unsolvable,
+    /// The search stopped after the given number of words with games left unexpanded, so a solution may
+    /// need more words.
+    DepthLimitReached(usize),
+}
+
+impl fmt::Display for NoSolution {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsolvable => write!(f, "the game can't be solved"),
+            Self::DepthLimitReached(depth) => write!(f, "no solution within {} words", depth),
+        }
+    }
+}
+
+impl std::error::Error for NoSolution {}
+
+/// How far `Solver::solve_with_progress` has got, given after each word is searched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SolveProgress {
+    /// The number of words searched on top of the game so far.
+    pub depth: usize,
+    /// The number of unfinished games the next word will be searched from.
+    pub frontier_len: usize,
+    /// The number of solutions found so far.
+    pub solutions_found: usize,
+}
+
+/// The solutions found by `Solver::solve_outcome`, and whether the search was cut short.
+#[derive(Clone, Debug)]
+pub struct SolveOutcome {
+    /// The solutions, in the same order as `Solver::solve`.
+    pub solutions: Vec<Vec<String>>,
+    /// Whether the deadline passed before the search finished, in which case better solutions may have been
+    /// missed, or an empty `solutions` doesn't mean there are none.
+    pub timed_out: bool,
+}
+
+/// The solutions for a game, along with the board they solve.
+#[derive(Clone, Debug, Serialize)]
+pub struct SolveReport {
+    /// The letters of each side, in the same order as `Sides::from_sides`.
+    pub board: [String; 4],
+    pub par: Option<usize>,
+    /// The solutions, in the same order as `Solver::solve` unless the report was built with another order.
+    pub solutions: Vec<RatedSolution>,
+}
+
+/// A solution with its length metrics.
+#[derive(Clone, Debug, Serialize)]
+pub struct RatedSolution {
+    pub words: Vec<String>,
+    pub total_letters: usize,
+    pub word_count: usize,
+    /// Whether the solution uses fewer words than par, if par is known.
+    pub beats_par: Option<bool>,
+    /// The least common word in the solution; see `Solver::rarest_word`.
+    pub rarest_word: Option<String>,
+}
+
+/// Letters that appear in few dictionary words.
+const RARE_LETTERS: [char; 6] = ['J', 'K', 'Q', 'V', 'X', 'Z'];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DifficultyLevel {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// How hard a game is, along with the metrics used to rate it.
+#[derive(Clone, Debug)]
+pub struct Difficulty {
+    pub level: DifficultyLevel,
+    /// The number of solutions that use two words.
+    pub two_word_solutions: usize,
+    /// The total character count of the shortest solution, if any was found.
+    pub shortest_solution: Option<usize>,
+    /// The number of board letters in `RARE_LETTERS`.
+    pub rare_letters: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_game() -> Game {
+        let sides = Sides::from_str("DKI", "JTA", "CLV", "ERO");
+        Game::new(sides)
+    }
+
+    /// The sample board with a small dictionary. Its only two-word solution is OKRA ADJECTIVAL, and its
+    /// three-word solutions are JIVED DOLT TRACK and VELDT TRACK KOJI. ZEBRA and KID can't be played.
+    fn fixture_game() -> Game {
+        let words = ["OKRA", "ADJECTIVAL", "JIVED", "DOLT", "TRACK", "KOJI", "VELDT", "ZEBRA", "KID", "LOCK"];
+        Game::with_dictionary(setup_game().sides().clone(), words.map(String::from).to_vec())
+    }
+
+    fn solution(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn test_fixture_possible_words() {
+        let mut game = fixture_game();
+        let mut words = game.possible_words().into_iter().collect::<Vec<String>>();
+        words.sort();
+        assert_eq!(words, solution(&["ADJECTIVAL", "DOLT", "JIVED", "KOJI", "LOCK", "OKRA", "TRACK", "VELDT"]));
+
+        game.play_word("DOLT");
+        assert_eq!(game.possible_words(), HashSet::from(["TRACK".to_string()]));
+    }
+
+    #[test]
+    fn test_longest_word() {
+        assert_eq!(fixture_game().longest_word(), Some("ADJECTIVAL".to_string()));
+
+        let game = Game::with_dictionary(setup_game().sides().clone(), ["TRACK", "JIVED", "VELDT", "ZEBRA"].map(String::from).to_vec());
+        assert_eq!(game.longest_word(), Some("JIVED".to_string()));
+
+        let game = Game::with_dictionary(setup_game().sides().clone(), vec!["ZEBRA".to_string()]);
+        assert_eq!(game.longest_word(), None);
+    }
+
+    #[test]
+    fn test_best_next_words() {
+        let mut game = fixture_game();
+        assert_eq!(game.best_next_words(3), vec![
+            ("ADJECTIVAL".to_string(), 9),
+            ("JIVED".to_string(), 5),
+            ("TRACK".to_string(), 5),
+        ]);
+
+        game.play_word("OKRA");
+        assert_eq!(game.best_next_words(10), vec![("ADJECTIVAL".to_string(), 8)]);
+        assert!(game.best_next_words(0).is_empty());
+        assert_eq!(game.new_letters_if_played("LOCK"), 2);
+        assert_eq!(game.hint(), Some("ADJECTIVAL".to_string()));
+
+        let mut game = Game::with_dictionary(setup_game().sides().clone(), vec!["OKRA".to_string()]);
+        game.play_word("OKRA");
+        assert_eq!(game.hint(), None);
+    }
+
+    #[test]
+    fn test_words_by_length() {
+        let words_by_length = fixture_game().words_by_length();
+        assert_eq!(words_by_length.keys().copied().collect::<Vec<usize>>(), vec![4, 5, 10]);
+        assert_eq!(words_by_length[&4], vec!["DOLT", "KOJI", "LOCK", "OKRA"]);
+        assert_eq!(words_by_length[&5], vec!["JIVED", "TRACK", "VELDT"]);
+        assert_eq!(words_by_length[&10], vec!["ADJECTIVAL"]);
+
+        let mut game = fixture_game();
+        game.words_by_length();
+        game.set_min_word_len(5);
+        assert_eq!(game.words_by_length().keys().copied().collect::<Vec<usize>>(), vec![5, 10]);
+        game.set_dictionary(vec!["okra".to_string(), "adjectival".to_string()]);
+        assert_eq!(game.words_by_length(), BTreeMap::from([(10, vec!["ADJECTIVAL".to_string()])]));
+    }
+
+    #[test]
+    fn test_rank_openers() {
+        let openers = Solver::rank_openers(&fixture_game());
+        assert_eq!(openers[0], ("ADJECTIVAL".to_string(), 91));
+        assert_eq!(openers.len(), 8);
+        assert!(openers.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let openers = Solver::rank_openers(&setup_game());
+        assert_eq!(openers.len(), MAX_OPENERS);
+    }
+
+    #[test]
+    fn test_solve_by_opener() {
+        let openers = ["OKRA", "JIVED", "KID", "ZEBRA", "TRACK"].map(String::from);
+        let by_opener = Solver::solve_by_opener(&fixture_game(), &openers);
+        assert_eq!(by_opener.len(), 5);
+        assert_eq!(by_opener["OKRA"], vec![solution(&["OKRA", "ADJECTIVAL"])]);
+        assert_eq!(by_opener["JIVED"], vec![solution(&["JIVED", "DOLT", "TRACK"])]);
+        assert!(by_opener["KID"].is_empty());
+        assert!(by_opener["ZEBRA"].is_empty());
+        assert!(by_opener["TRACK"].is_empty());
+    }
+
+    #[test]
+    fn test_unsolvable() {
+        let words = ["OKRA", "KOJI", "AKA"];
+        let game = Game::with_dictionary(setup_game().sides().clone(), words.map(String::from).to_vec());
+        assert!(Solver::solve(&game).is_empty());
+        assert_eq!(Solver::try_solve(&game), Err(NoSolution::Unsolvable));
+        assert_eq!(Solver::min_word_count(&game), None);
+
+        assert_eq!(Solver::try_solve(&fixture_game()), Ok(vec![solution(&["OKRA", "ADJECTIVAL"])]));
+    }
+
+    #[test]
+    fn test_fixture_solve() {
+        let game = fixture_game();
+        assert_eq!(Solver::solve(&game), vec![solution(&["OKRA", "ADJECTIVAL"])]);
+        assert_eq!(Solver::solve_game(&game, 3), vec![
+            solution(&["JIVED", "DOLT", "TRACK"]),
+            solution(&["OKRA", "ADJECTIVAL"]),
+            solution(&["VELDT", "TRACK", "KOJI"]),
+        ]);
+        assert_eq!(Solver::min_word_count(&game), Some(2));
+    }
+
+    #[test]
+    fn test_from_clockwise() {
+        let sides = Sides::from_clockwise("DKIJTAVLCORE").unwrap();
+        let expected = setup_game().sides().clone();
+        assert_eq!(sides.top(), expected.top());
+        assert_eq!(sides.right(), expected.right());
+        assert_eq!(sides.bottom(), expected.bottom());
+        assert_eq!(sides.left(), expected.left());
+
+        assert!(matches!(Sides::from_clockwise("DKIJTAVLCOR"), Err(SidesError::WrongLetterCount(11))));
+        assert!(matches!(Sides::from_clockwise("DKIJTAVLCORD"), Err(SidesError::DuplicateLetter('D'))));
+    }
+
+    #[test]
+    fn test_has_only_board_letters() {
+        let game = setup_game();
+        assert!(game.sides.has_only_board_letters("OKRA"));
+        assert!(game.sides.has_only_board_letters("KID"));
+        assert!(!game.sides.has_only_board_letters("ZEBRA"));
+        assert!(!game.sides.has_only_board_letters("okra"));
+
+        let unfiltered = words::WORDS
+            .iter()
+            .filter(|w| game.check_dict_word(w))
+            .map(|w| w.to_string())
+            .collect::<HashSet<String>>();
+        assert_eq!(game.possible_words(), unfiltered);
+
+        let lowercase = Sides::from_str("dki", "jta", "clv", "ero");
+        assert!(lowercase.has_only_board_letters("okra"));
+        assert!(!lowercase.has_only_board_letters("OKRA"));
+    }
+
+    #[test]
+    fn test_letter_sides() {
+        let sides = setup_game().sides().clone();
+        let letter_sides = sides.letter_sides();
+        assert_eq!(letter_sides.len(), 12);
+
+        for side in [Side::Top, Side::Right, Side::Bottom, Side::Left] {
+            for &letter in sides.get_letters(&side) {
+                assert_eq!(letter_sides[&letter], side);
+                assert_eq!(sides.get_side(letter), Some(side));
+            }
+        }
+        assert_eq!(sides.get_side('Z'), None);
+
+        let game = Game::new(sides.clone());
+        assert_eq!(game.sides_used_by_word("KID"), vec![Side::Top, Side::Top, Side::Top]);
+        assert_eq!(game.sides_used_by_word("OKRA"), vec![Side::Left, Side::Top, Side::Left, Side::Right]);
+        assert_eq!(game.sides_used_by_word("ZED"), vec![Side::Left, Side::Top]);
+        assert_eq!(letter_sides.values().copied().collect::<HashSet<Side>>().len(), 4);
+    }
+
+    #[test]
+    fn test_is_possibly_solvable() {
+        assert!(fixture_game().is_possibly_solvable());
+
+        let words = fixture_game().board_words().to_vec();
+        let game = Game::with_dictionary(Sides::from_str("QKI", "JTA", "CLV", "ERO"), words);
+        assert!(!game.is_possibly_solvable());
+        assert!(Solver::solve(&game).is_empty());
+
+        let mut game = fixture_game();
+        game.play_word("OKRA");
+        game.play_word("ADJECTIVAL");
+        assert!(game.is_possibly_solvable());
+    }
+
+    #[test]
+    fn test_count_solutions() {
+        let game = fixture_game();
+        for max_words in 0..4 {
+            assert_eq!(Solver::count_solutions(&game, max_words), Solver::solve_within(&game, max_words).len());
+        }
+        assert!(Solver::count_solutions(&game, 2) > 0);
+    }
+
+    #[test]
+    fn test_format_share() {
+        let game = fixture_game();
+        let share = Solver::format_share(&game, &solution(&["OKRA", "ADJECTIVAL"]));
+        assert_eq!(share, "Letter Boxed DKI-JTA-CLV-ERO\n2 words, 14 letters\n⬛🟩🟩🟩⬛\n🟩⬛⬛⬛🟩\n🟩⬛⬛⬛🟩\n🟩⬛⬛⬛🟩\n⬛🟩🟩🟩⬛");
+
+        let share = Solver::format_share(&game, &solution(&["KID"]));
+        assert!(share.starts_with("Letter Boxed DKI-JTA-CLV-ERO\n1 word, 3 letters\n⬛🟩🟩🟩⬛\n⬜⬛⬛⬛⬜\n"));
+        assert!(share.lines().all(|line| line == line.trim_end()));
+    }
+
+    #[test]
+    fn test_unsolvable_board_entry_points() {
+        let game = Game::new(Sides::from_shareable("QZX JTA CLV ERO").unwrap());
+        assert!(!game.is_possibly_solvable());
+
+        assert!(Solver::solve(&game).is_empty());
+        assert!(Solver::solve_within(&game, 3).is_empty());
+        assert!(matches!(Solver::try_solve(&game), Err(NoSolution::Unsolvable)));
+        assert!(Solver::solve_k(&game, 5).is_empty());
+        assert_eq!(Solver::solve_astar(&game), None);
+        assert!(Solver::solve_optimal(&game).is_empty());
+        assert!(Solver::solve_no_letter_reuse(&game).is_empty());
+        assert_eq!(Solver::solve_by_opener(&game, &solution(&["JOLT"]))[&"JOLT".to_string()], Vec::<Vec<String>>::new());
+        assert!(Solver::length_distribution(&game).is_empty());
+        assert!(!Solver::is_two_word_solvable(&game));
+        assert_eq!(Solver::min_word_count(&game), None);
+        assert_eq!(Solver::count_solutions(&game, 3), 0);
+    }
+
+    #[test]
+    fn test_depth_limit_counts_from_start() {
+        let words = ["DOLT", "TRACK", "KOD", "DEVJI"].map(String::from).to_vec();
+        let mut game = Game::with_dictionary(setup_game().sides().clone(), words);
+        game.set_allow_reuse(true);
+        for _ in 0..4 {
+            assert!(game.apply_words(&solution(&["DOLT", "TRACK", "KOD"])).is_ok());
+        }
+        assert!(game.previous_words().len() > MAX_DEPTH);
+
+        let expected = game.previous_words().iter().cloned().chain(["DEVJI".to_string()]).collect::<Vec<String>>();
+        assert_eq!(Solver::solve_k(&game, 1), vec![expected.clone()]);
+        assert_eq!(Solver::solve_astar(&game), Some(expected));
+    }
+
+    #[test]
+    fn test_side_usage() {
+        let mut game = fixture_game();
+        assert!(game.side_usage().values().all(|&count| count == 0));
+
+        game.play_word("OKRA");
+        game.play_word("ADJECTIVAL");
+        let usage = game.side_usage();
+        assert_eq!(usage.values().sum::<usize>(), 14);
+        assert_eq!(usage[&Side::Top], 3);
+        assert_eq!(usage[&Side::Right], 5);
+        assert_eq!(usage[&Side::Bottom], 3);
+        assert_eq!(usage[&Side::Left], 3);
+    }
+
+    #[test]
+    fn test_with_side() {
+        let sides = setup_game().sides().clone();
+        let edited = sides.with_side(Side::Top, ['B', 'K', 'I']).unwrap();
+        assert_eq!(edited.to_strings(), ["BKI", "JTA", "CLV", "ERO"].map(String::from));
+        assert_eq!(edited.get_side('B'), Some(Side::Top));
+        assert_eq!(edited.get_side('D'), None);
+        assert_eq!(sides.top(), ['D', 'K', 'I']);
+
+        assert!(matches!(sides.with_side(Side::Left, ['E', 'R', 'A']), Err(SidesError::DuplicateLetter('A'))));
+        assert!(matches!(sides.with_side(Side::Left, ['E', 'E', 'O']), Err(SidesError::DuplicateLetter('E'))));
+
+        let swapped = sides.swap_side(Side::Top, Side::Bottom);
+        assert_eq!(swapped.to_strings(), ["CLV", "JTA", "DKI", "ERO"].map(String::from));
+        assert_eq!(swapped.get_side('D'), Some(Side::Bottom));
+        assert_eq!(swapped.fingerprint(), sides.fingerprint());
+    }
+
+    #[test]
+    fn test_sorted_letters() {
+        assert_eq!(setup_game().sides.sorted_letters(), vec!['A', 'C', 'D', 'E', 'I', 'J', 'K', 'L', 'O', 'R', 'T', 'V']);
+    }
+
+    #[cfg(feature = "wildcards")]
+    #[test]
+    fn test_wildcards() {
+        let sides = Sides::from_str("DKI", "JTA", "CLV", "ER*");
+        assert_eq!(sides.get_side('O'), Some(Side::Left));
+        assert_eq!(sides.get_side('D'), Some(Side::Top));
+        assert!(sides.has_only_board_letters("OKRA"));
+
+        let mut game = Game::new(sides.clone());
+        assert!(matches!(game.validate_word("ZEBRA"), Err(WordRejection::ConsecutiveSameSide { letter: 'E', side: Side::Left })));
+        assert!(matches!(game.validate_word("EZA"), Err(WordRejection::ConsecutiveSameSide { letter: 'Z', side: Side::Left })));
+        assert!(matches!(game.validate_word("AQZA"), Err(WordRejection::ConsecutiveSameSide { letter: 'Z', side: Side::Left })));
+        assert_eq!(game.play_word_detailed("OKRA"), vec!['*', 'K', 'R', 'A']);
+        assert_eq!(game.play_word("ADJECTIVAL"), 8);
+        assert!(game.check_win());
+        assert_eq!(game.coverage_map()[&'*'], 0);
+
+        let game = Game::with_dictionary(sides, ["OKRA", "ADJECTIVAL", "JIVED", "DOLT", "TRACK"].map(String::from).to_vec());
+        assert!(Solver::is_two_word_solvable(&game));
+        assert_eq!(Solver::solve(&game), vec![solution(&["OKRA", "ADJECTIVAL"])]);
+    }
+
+    #[test]
+    fn test_reachable() {
+        let sides = setup_game().sides().clone();
+        assert_eq!(sides.legal_next('D'), vec!['J', 'T', 'A', 'C', 'L', 'V', 'E', 'R', 'O']);
+        assert!(sides.legal_next('Z').is_empty());
+
+        assert_eq!(sides.reachable('D', 0), HashSet::from(['D']));
+        assert_eq!(sides.reachable('D', 1), HashSet::from(['D', 'J', 'T', 'A', 'C', 'L', 'V', 'E', 'R', 'O']));
+        assert_eq!(sides.reachable('D', 2), sides.all_chars);
+        assert!(sides.reachable('Z', 2).is_empty());
+    }
+
+    #[test]
+    fn test_from_json_array() {
+        let sides = Sides::from_json_array(&serde_json::json!(["DKI", "JTA", "CLV", "ERO"])).unwrap();
+        assert_eq!(sides.to_strings(), ["DKI", "JTA", "CLV", "ERO"].map(String::from));
+
+        let error = Sides::from_json_array(&serde_json::json!(["DKI", "JTA", "CLV", "ERA"])).unwrap_err();
+        assert_eq!(error.to_string(), "'A' appears more than once");
+        let error = Sides::from_json_array(&serde_json::json!(["DKI", "JTA", "CL", "ERO"])).unwrap_err();
+        assert_eq!(error.to_string(), "expected 3 letters on the bottom side, got 2");
+        assert!(Sides::from_json_array(&serde_json::json!(["DKI", "JTA", "CLV"])).is_err());
+        assert!(Sides::from_json_array(&serde_json::json!(["DKI", "JTA", "CLV", 4])).is_err());
+        assert!(Sides::from_json_array(&serde_json::json!("DKIJTACLVERO")).is_err());
+    }
+
+    #[test]
+    fn test_signature() {
+        let sides = setup_game().sides().clone();
+        assert_eq!(sides.fingerprint(), "AJT-CLV-DIK-EOR");
+
+        let shuffled = Sides::from_str("ORE", "IKD", "VLC", "TAJ");
+        assert_eq!(shuffled.fingerprint(), sides.fingerprint());
+        assert_eq!(shuffled.signature(), sides.signature());
+
+        let other = Sides::from_str("DKI", "JTA", "CLV", "ERS");
+        assert_ne!(other.signature(), sides.signature());
+        assert_eq!(sides.signature() >> 60, 0);
+
+        let lowercase = Sides::from_str("dki", "jta", "clv", "ero");
+        assert_eq!(lowercase.signature() >> 63, 1);
+
+        let large = Sides::try_new("ABCDE", "FGHIJ", "KLMNO", "PQRST").unwrap();
+        let other = Sides::try_new("ZBCDE", "FGHIJ", "KLMNO", "PQRST").unwrap();
+        assert_ne!(large.signature(), other.signature());
+        assert_eq!(large.signature() >> 63, 1);
+        assert_eq!(large.signature(), Sides::try_new("PQRST", "KLMNO", "FGHIJ", "EDCBA").unwrap().signature());
+    }
+
+    struct FixedSource(GameData);
+
+    impl GameSource for FixedSource {
+        fn fetch_today(&self) -> Result<GameData, DataError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn sample_game_data() -> GameData {
+        GameData {
+            date: "2024-03-27".to_string(),
+            dictionary: vec!["OKRA".to_string(), "ADJECTIVAL".to_string()],
+            editor: "Sam Ezersky".to_string(),
+            editorImage: String::new(),
+            expiration: 1711598400,
+            id: 1234,
+            isFree: false,
+            ourSolution: vec!["OKRA".to_string(), "ADJECTIVAL".to_string()],
+            par: 5,
+            printDate: "2024-03-27".to_string(),
+            sides: ["DKI", "JTA", "CLV", "ERO"].map(String::from),
+            yesterdaysSides: ["ABC", "DEF", "GHI", "JKL"].map(String::from),
+            yesterdaysSolution: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_game_source() {
+        let source = FixedSource(sample_game_data());
+
+        let today = Game::today_from(&source).unwrap();
+        assert_eq!(today.sides().to_strings(), ["DKI", "JTA", "CLV", "ERO"].map(String::from));
+        assert_eq!(today.par(), Some(5));
+
+        let yesterday = Game::yesterday_from(&source).unwrap();
+        assert_eq!(yesterday.sides().top(), ['A', 'B', 'C']);
+        assert_eq!(yesterday.par(), None);
+    }
+
+    #[test]
+    fn test_try_from_game_data() {
+        let game_data = sample_game_data();
+        let game = Game::try_from(&game_data).unwrap();
+        assert_eq!(game.sides().to_strings(), ["DKI", "JTA", "CLV", "ERO"].map(String::from));
+        assert_eq!(game.par(), Some(5));
+        assert_eq!(game.possible_words(), HashSet::from(["OKRA".to_string(), "ADJECTIVAL".to_string()]));
+
+        let mut no_dictionary = sample_game_data();
+        no_dictionary.dictionary.clear();
+        assert!(Game::try_from(&no_dictionary).unwrap().check_dict_word("DOLT"));
+
+        let mut malformed = sample_game_data();
+        malformed.sides[2] = "CLVX".to_string();
+        let mismatched = |error: &SidesError| {
+            matches!(error, SidesError::MismatchedSideLength { side: Side::Bottom, expected: 3, got: 4 })
+        };
+        assert!(Sides::try_from(&malformed).is_err_and(|error| mismatched(&error)));
+        assert!(Game::try_from(&malformed).is_err_and(|error| mismatched(&error)));
+        assert!(matches!(Game::today_from(&FixedSource(malformed)), Err(DataError::InvalidBoard(error)) if mismatched(&error)));
+
+        let mut malformed = sample_game_data();
+        malformed.yesterdaysSides[0] = "ABD".to_string();
+        assert!(Game::today_from(&FixedSource(malformed.clone())).is_ok());
+        assert!(matches!(Game::yesterday_from(&FixedSource(malformed)), Err(DataError::InvalidBoard(SidesError::DuplicateLetter('D')))));
+    }
+
+    #[test]
+    fn test_deserialize_hydrates() {
+        let game: Game = serde_json::from_str(r#"{"sides":["DKI","JTA","CLV","ERO"],"previous_words":["OKRA"],"par":null}"#).unwrap();
+        let mut played = setup_game();
+        played.play_word("OKRA");
+        assert_eq!(game.remaining_letters, played.remaining_letters);
+        assert_eq!(game.used_letters, played.used_letters);
+        assert!(!game.check_win());
+
+        let game: Game = serde_json::from_str(r#"{"sides":["DKI","JTA","CLV","ERO"],"previous_words":["OKRA","ADJECTIVAL"],"par":5}"#).unwrap();
+        assert!(game.check_win());
+        assert!(game.remaining_letters.is_empty());
+        assert_eq!(game.par(), Some(5));
+
+        assert!(serde_json::from_str::<Game>(r#"{"sides":["DKI","JTA","CLV","EROS"],"previous_words":[],"par":null}"#).is_err());
+    }
+
+    #[test]
+    fn test_verify_history() {
+        let game: Game = serde_json::from_str(r#"{"sides":["DKI","JTA","CLV","ERO"],"previous_words":["OKRA","ADJECTIVAL"],"par":null}"#).unwrap();
+        assert!(game.verify_history().is_ok());
+
+        let game: Game = serde_json::from_str(r#"{"sides":["DKI","JTA","CLV","ERO"],"previous_words":["OKRA","KID","DOLT"],"par":null}"#).unwrap();
+        assert!(matches!(game.verify_history(), Err((1, WordRejection::NotStartingWithLastLetter { expected: 'A' }))));
+
+        let game: Game = serde_json::from_str(r#"{"sides":["DKI","JTA","CLV","ERO"],"previous_words":["ZEBRA"],"par":null}"#).unwrap();
+        assert!(matches!(game.verify_history(), Err((0, WordRejection::LetterNotOnBoard('Z')))));
+    }
+
+    #[test]
+    fn test_mark_word() {
+        let mut game = setup_game();
+        assert_eq!(game.play_word("OKRA"), 4);
+        assert_eq!(game.play_word("OKRA"), 0);
+        assert_eq!(game.play_word("ADJECTIVAL"), 8);
+    }
+
+    #[test]
+    fn test_four_letter_sides() {
+        let sides = Sides::try_new("MNKP", "HOAI", "BCRS", "TGEW").unwrap();
+        assert_eq!(sides.side_len(), 4);
+        assert!(matches!(
+            Sides::try_new("MNKP", "HOAI", "BCR", "TGEW"),
+            Err(SidesError::MismatchedSideLength { side: Side::Bottom, expected: 4, got: 3 })
+        ));
+        assert!(matches!(
+            sides.with_side(Side::Top, ['M', 'N', 'K']),
+            Err(SidesError::MismatchedSideLength { side: Side::Top, expected: 4, got: 3 })
+        ));
+        let error = Sides::try_new("MNKP", "HOAI", "BCR", "TGEW").unwrap_err();
+        assert_eq!(error.to_string(), "expected 4 letters on the bottom side, got 3");
+        assert!(matches!(
+            Sides::try_new("ABCDEFGHI", "JKLMNOPQR", "STUVWXYZa", "bcdefghij"),
+            Err(SidesError::TooManyLetters(36))
+        ));
+
+        let game = Game::with_dictionary(sides, ["PIG", "PIGMENT", "THROWBACKS"].map(String::from).to_vec());
+        assert_eq!(game.format_board(), "  M N K P  \nT         H \nG         O \nE         A \nW         I \n  B C R S  ");
+        assert_eq!(game.sides().to_string(), game.format_board());
+
+        let solutions = Solver::solve(&game);
+        assert_eq!(solutions, vec![vec!["PIGMENT".to_string(), "THROWBACKS".to_string()]]);
+
+        let mut game = game;
+        game.play_word("PIGMENT");
+        assert!(!game.check_win());
+        game.play_word("THROWBACKS");
+        assert!(game.check_win());
+    }
+
+    #[test]
+    fn test_shareable() {
+        let expected = setup_game().sides().to_strings();
+        for board in ["dki-jta-clv-ero", "DKI JTA CLV ERO", "DKI, JTA, CLV, ERO", "DKIJTAVLCORE"] {
+            assert_eq!(Sides::from_shareable(board).unwrap().to_strings(), expected);
+        }
+
+        let sides = setup_game().sides().clone();
+        assert_eq!(sides.to_shareable(), "DKI-JTA-CLV-ERO");
+        assert_eq!(Sides::from_shareable(&sides.to_shareable()).unwrap().to_strings(), expected);
+
+        assert!(matches!(Sides::from_shareable("DKI JTA CLV"), Err(SidesError::WrongSideCount(3))));
+        assert!(matches!(Sides::from_shareable("DKI JTA CLV ER"), Err(SidesError::MismatchedSideLength { side: Side::Left, expected: 3, got: 2 })));
+        assert!(matches!(Sides::from_shareable("DKI JTA CLV ERD"), Err(SidesError::DuplicateLetter('D'))));
+    }
+
+    #[test]
+    fn test_display() {
+        let mut game = fixture_game();
+        assert_eq!(game.sides().to_string(), game.format_board());
+        assert_eq!(game.to_string(), format!("{}\nWords: none (remaining: A C D E I J K L O R T V)", game.format_board()));
+
+        game.play_word("OKRA");
+        game.play_word("ADJECTIVAL");
+        assert_eq!(game.to_string(), format!("{}\nWords: OKRA - ADJECTIVAL (remaining: none)", game.format_board()));
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(Sides::try_new("DKI", "JTA", "CLV", "ERO").unwrap().to_strings(), setup_game().sides.to_strings());
+        assert!(matches!(Sides::try_new("DK", "JTA", "CLV", "ERO"), Err(SidesError::MismatchedSideLength { side: Side::Top, expected: 3, got: 2 })));
+        assert!(matches!(
+            Sides::try_new("DKI", "JTAS", "CLV", "ERO"),
+            Err(SidesError::MismatchedSideLength { side: Side::Right, expected: 3, got: 4 })
+        ));
+        assert!(matches!(Sides::try_new("", "", "", ""), Err(SidesError::WrongSideLength { side: Side::Top, got: 0 })));
+        let error = Sides::try_new("ABC", "", "", "").unwrap_err();
+        assert!(matches!(error, SidesError::WrongSideLength { side: Side::Right, got: 0 }));
+        assert_eq!(error.to_string(), "expected 1 to 8 letters on the right side, got 0");
+        assert!(matches!(Sides::try_new("DKI", "JTA", "CLV", "ERD"), Err(SidesError::DuplicateLetter('D'))));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid sides")]
+    fn test_from_str_panics() {
+        Sides::from_str("DKI", "JTA", "CLV", "EROS");
+    }
+
+    #[test]
+    fn test_allow_reuse() {
+        let mut game = setup_game();
+        game.play_word("ARIA");
+        assert!(!game.is_fresh_word("ARIA"));
+        assert!(game.is_fresh_word("ADJECTIVAL"));
+        assert!(game.check_dict_word("ARIA"));
+
+        game.set_allow_reuse(false);
+        assert!(!game.check_dict_word("ARIA"));
+        assert!(matches!(game.validate_word("ARIA"), Err(WordRejection::AlreadyPlayed)));
+        assert!(game.check_dict_word("ADJECTIVAL"));
+
+        for solution in Solver::solve(&fixture_game()) {
+            assert_eq!(solution.iter().collect::<HashSet<_>>().len(), solution.len());
+        }
+    }
+
+    #[test]
+    fn test_undo_word() {
+        let mut game = setup_game();
+        assert_eq!(game.undo_word(), None);
+
+        game.play_word("OKRA");
+        game.play_word("ARIA");
+        game.play_word("ADJECTIVAL");
+        assert!(game.check_win());
+
+        assert_eq!(game.undo_word(), Some("ADJECTIVAL".to_string()));
+        assert_eq!(game.undo_word(), Some("ARIA".to_string()));
+
+        let mut expected = setup_game();
+        expected.play_word("OKRA");
+        assert_eq!(game.previous_words, expected.previous_words);
+        assert_eq!(game.used_letters, expected.used_letters);
+        assert_eq!(game.remaining_letters, expected.remaining_letters);
+    }
+
+    #[test]
+    fn test_possible_words_parallel() {
+        let mut game = setup_game();
+        for word in ["OKRA", "ARIA"] {
+            game.play_word(word);
+            let sequential = words::WORDS
+                .iter()
+                .filter(|w| game.check_dict_word(w))
+                .map(|w| w.to_string())
+                .collect::<HashSet<String>>();
+            assert_eq!(game.possible_words(), sequential);
+        }
+    }
+
+    #[test]
+    fn test_solution_sort() {
+        let solution = |words: &[&str]| words.iter().map(|w| w.to_string()).collect::<Vec<String>>();
+        let mut solutions = [
+            solution(&["OKRA", "ADJECTIVAL"]),
+            solution(&["AB", "BC", "CD"]),
+            solution(&["ABCDEFGHIJKLMNOP"]),
+            solution(&["AB", "BC", "CA"]),
+        ];
+
+        solutions.sort_by(|a, b| SolutionSort::TotalChars.compare(a, b));
+        assert_eq!(solutions[0], solution(&["AB", "BC", "CA"]));
+        assert_eq!(solutions[3], solution(&["ABCDEFGHIJKLMNOP"]));
+
+        solutions.sort_by(|a, b| SolutionSort::WordCount.compare(a, b));
+        assert_eq!(solutions[..2], [solution(&["ABCDEFGHIJKLMNOP"]), solution(&["OKRA", "ADJECTIVAL"])]);
+
+        solutions.sort_by(|a, b| SolutionSort::Lexicographic.compare(a, b));
+        assert_eq!(solutions[0], solution(&["AB", "BC", "CA"]));
+        assert_eq!(solutions[3], solution(&["OKRA", "ADJECTIVAL"]));
+
+        let game = fixture_game();
+        assert_eq!(Solver::solve_sorted(&game, SolutionSort::TotalChars), Solver::solve(&game));
+    }
+
+    #[test]
+    fn test_iter_possible_words() {
+        let mut game = setup_game();
+        game.play_word("OKRA");
+        assert_eq!(game.iter_possible_words().collect::<HashSet<&str>>().len(), game.possible_words().len());
+
+        let first = game.iter_possible_words().take(3).collect::<Vec<&str>>();
+        assert_eq!(first.len(), 3);
+        assert!(first.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(game.iter_possible_words().filter(|w| w.starts_with("ADJ")).any(|w| w == "ADJECTIVAL"));
+    }
+
+    #[test]
+    fn test_pangrams() {
+        let game = Game::new(Sides::from_str("COS", "ANT", "ILD", "PRH"));
+        assert_eq!(game.pangrams(), vec!["ACHONDROPLASTIC".to_string()]);
+
+        let mut game = game;
+        game.play_word("ACHONDROPLASTIC");
+        assert!(game.check_win());
+
+        assert!(setup_game().pangrams().is_empty());
+    }
+
+    #[test]
+    fn test_solve_within() {
+        let mut game = fixture_game();
+        assert!(Solver::solve_within(&game, 1).is_empty());
+        assert_eq!(Solver::solve_within(&game, 2), Solver::solve(&game));
+        assert_eq!(Solver::solve_within(&game, 5), Solver::solve(&game));
+
+        game.play_word("OKRA");
+        assert!(Solver::solve_within(&game, 1).is_empty());
+        assert_eq!(Solver::solve_within(&game, 2), vec![vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]]);
+    }
+
+    #[test]
+    fn test_solve_with_progress() {
+        let game = fixture_game();
+        let progress = Mutex::new(Vec::new());
+        let solutions = Solver::solve_with_progress(&game, 0, |p| progress.lock().unwrap().push(p));
+        assert_eq!(solutions, Solver::solve(&game));
+
+        let progress = progress.into_inner().unwrap();
+        assert_eq!(progress.len(), 2);
+        assert_eq!(progress.iter().map(|p| p.depth).collect::<Vec<usize>>(), vec![1, 2]);
+        assert_eq!(progress[0].solutions_found, 0);
+        assert!(progress[0].frontier_len > 0);
+        assert_eq!(progress[1].solutions_found, solutions.len());
+    }
+
+    #[test]
+    fn test_deadline() {
+        let game = fixture_game();
+        let config = SolverConfig { deadline: Some(Duration::ZERO), ..Default::default() };
+        let outcome = Solver::solve_outcome(&game, 0, &config);
+        assert!(outcome.timed_out);
+        assert!(outcome.solutions.is_empty());
+
+        let config = SolverConfig { deadline: Some(Duration::from_secs(60)), ..Default::default() };
+        let outcome = Solver::solve_outcome(&game, 0, &config);
+        assert!(!outcome.timed_out);
+        assert_eq!(outcome.solutions, Solver::solve(&game));
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let game = fixture_game();
+        let config = SolverConfig { max_depth: Some(1), ..Default::default() };
+        assert!(Solver::solve_with_config(&game, 0, &config).is_empty());
+
+        let config = SolverConfig { max_depth: Some(2), ..Default::default() };
+        assert!(Solver::solve_with_config(&game, 0, &config).contains(&vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]));
+    }
+
+    #[test]
+    fn test_dictionary_casing() {
+        let mut game = Game::with_dictionary(setup_game().sides().clone(), vec!["okra".to_string(), "Adjectival".to_string()]);
+        assert!(game.check_dict_word("OKRA"));
+        assert_eq!(game.possible_words(), HashSet::from(["ADJECTIVAL".to_string(), "OKRA".to_string()]));
+
+        game.set_dictionary(vec!["dolt".to_string()]);
+        assert_eq!(game.possible_words(), HashSet::from(["DOLT".to_string()]));
+    }
+
+    #[test]
+    fn test_replayed_word_adds_no_letters() {
+        let mut game = setup_game();
+        assert_eq!(game.play_word("ARIA"), 3);
+        assert_eq!(game.new_letters_if_played("ARIA"), 0);
+        assert_eq!(game.play_word("ARIA"), 0);
+        assert!(matches!(game.try_play_word("ARIA"), Ok(0)));
+        assert_eq!(game.used_letters.len(), 3);
+    }
+
+    #[test]
+    fn test_play_word_detailed() {
+        let mut game = setup_game();
+        assert_eq!(game.play_word_detailed("OKRA"), vec!['O', 'K', 'R', 'A']);
+        assert_eq!(game.play_word_detailed("ADJECTIVAL"), vec!['D', 'J', 'E', 'C', 'T', 'I', 'V', 'L']);
+        assert!(game.play_word_detailed("LOCK").is_empty());
+        assert!(game.check_win());
+    }
+
+    #[test]
+    fn test_coverage_map() {
+        let mut game = setup_game();
+        assert!(game.coverage_map().is_empty());
+
+        game.play_word("OKRA");
+        game.play_word("ADJECTIVAL");
+        let coverage = game.coverage_map();
+        assert_eq!(coverage.len(), 12);
+        assert_eq!(coverage[&'A'], 0);
+        assert_eq!(coverage[&'K'], 0);
+        assert_eq!(coverage[&'D'], 1);
+        assert_eq!(coverage[&'L'], 1);
+    }
+
+    #[test]
+    fn test_try_play_word() {
+        let mut game = setup_game();
+        assert!(matches!(game.try_play_word("OKRA"), Ok(4)));
+        assert!(matches!(game.try_play_word("ADZ"), Err(WordRejection::LetterNotOnBoard('Z'))));
+        assert!(matches!(game.try_play_word("ATE"), Err(WordRejection::ConsecutiveSameSide { letter: 'T', side: Side::Right })));
+        assert!(matches!(game.try_play_word("KID"), Err(WordRejection::NotStartingWithLastLetter { expected: 'A' })));
+        assert_eq!(game.previous_words, vec!["OKRA".to_string()]);
+        assert_eq!(game.used_letters.len(), 4);
+    }
+
+    #[test]
+    fn test_check_dict_word_length() {
+        let mut game = setup_game();
+        for word in ["", "a", "ab", "OK"] {
+            assert!(!game.check_dict_word(word), "{:?} should be too short", word);
+            assert!(matches!(game.validate_word(word), Err(WordRejection::TooShort { min_len: 3 })));
+        }
+        assert!(game.check_dict_word("ODE"));
+        assert!(game.possible_words().iter().all(|w| w.len() >= MIN_WORD_LEN));
+
+        game.play_word("ODE");
+        assert!(!game.check_dict_word(""));
+        assert!(matches!(game.validate_word("EK"), Err(WordRejection::TooShort { .. })));
+    }
+
+    #[test]
+    fn test_min_word_len() {
+        let mut game = fixture_game();
+        assert_eq!(game.min_word_len(), MIN_WORD_LEN);
+        assert!(game.check_dict_word("DOLT"));
+
+        game.set_min_word_len(5);
+        assert!(matches!(game.validate_word("OKRA"), Err(WordRejection::TooShort { min_len: 5 })));
+        assert!(game.check_dict_word("ADJECTIVAL"));
+        assert!(!game.possible_words().contains("KOJI"));
+        assert!(Solver::solve(&game).is_empty());
+        assert_eq!(Solver::min_word_count(&game), None);
+
+        let stored: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+        assert_eq!(stored.min_word_len(), 5);
+    }
+
+    #[test]
+    fn test_validate_solution() {
+        let game = Game::try_from(&sample_game_data()).unwrap();
+        assert!(game.validate_solution(&sample_game_data().ourSolution).is_ok());
+        assert!(game.previous_words().is_empty());
+
+        let words = ["OKRA", "DOLT"].map(String::from);
+        let error = setup_game().validate_solution(&words).unwrap_err();
+        assert!(matches!(error, SolutionError::InvalidWord { index: 1, reason: WordRejection::NotStartingWithLastLetter { expected: 'A' } }));
+        assert_eq!(error.to_string(), "word 2 can't be played: word must start with 'A'");
+
+        assert!(matches!(setup_game().validate_solution(&["OKRA".to_string()]), Err(SolutionError::NotWon { remaining: 8 })));
+
+        let mut game = setup_game();
+        game.play_word("OKRA");
+        assert!(game.validate_solution(&["ADJECTIVAL".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn test_word_error() {
+        let mut game = setup_game();
+        assert!(matches!(game.validate_word("OK"), Err(WordRejection::TooShort { min_len: 3 })));
+        assert!(matches!(game.validate_word("ZEBRA"), Err(WordRejection::LetterNotOnBoard('Z'))));
+        assert!(matches!(game.validate_word("KID"), Err(WordRejection::ConsecutiveSameSide { letter: 'I', side: Side::Top })));
+
+        game.play_word("OKRA");
+        assert!(matches!(game.validate_word("DOLT"), Err(WordRejection::NotStartingWithLastLetter { expected: 'A' })));
+        assert_eq!(game.check_dict_word("ARIA"), game.validate_word("ARIA").is_ok());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let mut game = setup_game();
+        game.play_word("OKRA");
+        game.play_word("ARIA");
+
+        let stored: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+        assert_eq!(stored.previous_words, game.previous_words);
+        assert_eq!(stored.used_letters, game.used_letters);
+        assert_eq!(stored.remaining_letters, game.remaining_letters);
+        assert_eq!(stored.possible_words(), game.possible_words());
+
+        let game = Game::new(Sides::from_str("MNKP", "HOAI", "BCRS", "TGEW"));
+        let json = serde_json::to_string(&game).unwrap();
+        assert!(!json.contains("dictionary"));
+        let stored: Game = serde_json::from_str(&json).unwrap();
+        assert_eq!(stored.sides().to_strings(), game.sides().to_strings());
+
+        let mut game = fixture_game();
+        game.play_word("OKRA");
+        let stored: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+        assert_eq!(stored.possible_words(), game.possible_words());
+        assert_eq!(stored.possible_words(), HashSet::from(["ADJECTIVAL".to_string()]));
+    }
+
+    #[test]
+    fn test_rules() {
+        let game = setup_game();
+        assert_eq!(game.rules(), &Rules::default());
+        assert_eq!(game.rules().min_word_len, MIN_WORD_LEN);
+
+        let rules = Rules { max_word_len: Some(5), allow_reuse: false, ..Default::default() };
+        let mut game = Game::new_with_rules(setup_game().sides().clone(), rules.clone());
+        assert!(matches!(game.validate_word("ADJECTIVAL"), Err(WordRejection::TooLong { max_len: 5 })));
+        assert!(matches!(game.try_play_word("ARIA"), Ok(3)));
+        assert!(matches!(game.try_play_word("ARIA"), Err(WordRejection::AlreadyPlayed)));
+        assert_eq!(game.validation_stats().already_played, 1);
+
+        let stored: Game = serde_json::from_str(&serde_json::to_string(&game).unwrap()).unwrap();
+        assert_eq!(stored.rules(), &rules);
+
+        let mut game = setup_game();
+        game.play_word("ARIA");
+        assert!(game.check_dict_word("ARIA"));
+
+        let stored: Game = serde_json::from_str(r#"{"sides":["DKI","JTA","CLV","ERO"],"previous_words":[],"par":null,"rules":{"min_word_len":4}}"#).unwrap();
+        assert_eq!(stored.rules(), &Rules { min_word_len: 4, ..Default::default() });
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut game = setup_game();
+        assert_eq!(game.progress(), 0.0);
+        assert_eq!(game.letters_remaining_count(), 12);
+
+        game.play_word("OKRA");
+        assert_eq!(game.letters_remaining_count(), 8);
+        assert!((game.progress() - 4.0 / 12.0).abs() < f32::EPSILON);
+        assert_eq!(game.used_letters(), &HashSet::from(['O', 'K', 'R', 'A']));
+        assert!(!game.remaining_letters().contains(&'K'));
+        assert_eq!(game.previous_words(), ["OKRA".to_string()]);
+
+        game.play_word("ADJECTIVAL");
+        assert_eq!(game.progress(), 1.0);
+    }
+
+    #[test]
+    fn test_validation_stats() {
+        let mut game = setup_game();
+        let stats = game.validation_stats();
+        assert_eq!(stats.valid, game.possible_words().len());
+        assert_eq!(stats.not_starting_with_last_letter, 0);
+        assert_eq!(stats.valid + stats.too_short + stats.letter_not_on_board + stats.consecutive_same_side, words::WORDS.len());
+
+        game.play_word("OKRA");
+        let after_okra = game.validation_stats();
+        assert_eq!(after_okra.valid, game.possible_words().len());
+        assert!(after_okra.not_starting_with_last_letter > 0);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let game = setup_game();
+        let markdown = game.to_markdown(&[
+            vec!["OKRA".to_string(), "ADJECTIVAL".to_string()],
+            vec!["OVERDECK".to_string()],
+        ]);
+        assert!(markdown.starts_with("```\n  D K I  \n"));
+        assert!(markdown.contains("| 1 | OKRA - ADJECTIVAL | 2 | 14 |\n"));
+        assert!(markdown.ends_with("| 2 | OVERDECK | 1 | 8 |\n"));
+
+        assert!(game.to_markdown(&[]).ends_with("```\n\nNo solutions found.\n"));
+    }
+
+    #[test]
+    fn test_apply_words() {
+        let mut game = setup_game();
+        assert!(game.apply_words(&["OKRA".to_string(), "ADJECTIVAL".to_string()]).is_ok());
+        assert!(game.check_win());
+
+        let mut game = setup_game();
+        let result = game.apply_words(&["OKRA".to_string(), "KID".to_string()]);
+        assert!(matches!(result, Err((1, WordRejection::NotStartingWithLastLetter { expected: 'A' }))));
+        assert_eq!(game.previous_words, vec!["OKRA".to_string()]);
+    }
+
+    #[test]
+    fn test_next_start_letter() {
+        let mut game = setup_game();
+        assert_eq!(game.next_start_letter(), None);
+
+        game.play_word("OKRA");
+        assert_eq!(game.next_start_letter(), Some('A'));
+
+        let words_by_letter = game.words_by_first_letter();
+        let filtered = Solver::quick_filter_possible_words(&game, &words_by_letter)
+            .into_iter()
+            .cloned()
+            .collect::<HashSet<String>>();
+        assert!(!filtered.is_empty());
+        assert_eq!(filtered, game.fresh_possible_words());
+    }
+
+    #[test]
+    fn test_fresh_possible_words() {
+        let mut game = Game::with_dictionary(setup_game().sides().clone(), vec!["AKA".to_string(), "OKRA".to_string()]);
+        game.play_word("AKA");
+        assert_eq!(game.possible_words(), HashSet::from(["AKA".to_string()]));
+        assert!(game.fresh_possible_words().is_empty());
+        let words_by_letter = game.words_by_first_letter();
+        assert!(Solver::quick_filter_possible_words(&game, &words_by_letter).is_empty());
+    }
+
+    #[test]
+    fn test_possible_words_from() {
+        let mut game = setup_game();
+        let words_by_letter = game.words_by_first_letter();
+        assert_eq!(game.possible_words_from(None), game.possible_words());
+
+        game.play_word("OKRA");
+        let from_a = game.possible_words_from(Some('A'));
+        assert_eq!(from_a, game.possible_words());
+        assert_eq!(from_a, words_by_letter[&'A'].iter().cloned().collect::<HashSet<String>>());
+    }
+
+    #[test]
+    fn test_solve() {
+        let game = setup_game();
+        let solutions = Solver::solve(&game);
+
+        assert!(!solutions.is_empty());
+        assert!(solutions.contains(&vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]));
+    }
+
+    #[test]
+    fn test_solutions_replay() {
+        let game = setup_game();
+        for solution in Solver::solve(&game) {
+            let mut replay = Game::new(game.sides().clone());
+            assert!(replay.apply_words(&solution).is_ok(), "{:?} isn't legal", solution);
+            assert!(replay.check_win(), "{:?} doesn't win", solution);
+        }
+
+        let mut game = fixture_game();
+        game.play_word("VELDT");
+        let solutions = Solver::solve_game(&game, 3);
+        assert_eq!(solutions, vec![solution(&["VELDT", "TRACK", "KOJI"])]);
+        assert!(solutions.iter().all(|s| Solver::is_winning_solution(&game, s)));
+        assert!(!Solver::is_winning_solution(&game, &solution(&["VELDT", "TRACK"])));
+        assert!(!Solver::is_winning_solution(&game, &solution(&["OKRA", "KID"])));
+    }
+
+    #[test]
+    fn test_solve_after_unchecked_word() {
+        let mut game = Game::with_dictionary(Sides::from_str("ABC", "DEF", "GHI", "JKL"), vec!["DGJBEHKCFIL".to_string()]);
+        game.play_word("AD");
+        assert_eq!(Solver::solve(&game), vec![solution(&["AD", "DGJBEHKCFIL"])]);
+    }
+
+    #[test]
+    fn test_explain() {
+        let mut game = fixture_game();
+        game.set_par(Some(5));
+        assert_eq!(Solver::explain(&game, &solution(&["OKRA", "ADJECTIVAL"])), [
+            "Play OKRA — covers O,K,R,A; ends on A.",
+            "Play ADJECTIVAL starting with A — covers D,J,E,C,T,I,V,L; board complete.",
+            "Solved in 2 words, 3 under par (5).",
+        ].join("\n"));
+
+        assert_eq!(Solver::explain(&game, &solution(&["OKRA", "KID"])), [
+            "Play OKRA — covers O,K,R,A; ends on A.",
+            "Can't play KID: word must start with 'A'.",
+        ].join("\n"));
+
+        assert_eq!(Solver::explain(&game, &solution(&["TRACK"])), [
+            "Play TRACK — covers T,R,A,C,K; ends on K.",
+            "Not solved: 7 letters remaining.",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn test_difficulty() {
+        let difficulty = Solver::difficulty(&setup_game());
+        assert_eq!(difficulty.level, DifficultyLevel::Hard);
+        assert_eq!(difficulty.two_word_solutions, 2);
+        assert_eq!(difficulty.shortest_solution, Some(14));
+        assert_eq!(difficulty.rare_letters, 3);
+    }
+
+    #[test]
+    fn test_tie_break() {
+        let mut solutions = vec![
+            vec!["CAB".to_string(), "BAD".to_string()],
+            vec!["ABCDEF".to_string()],
+            vec!["AB".to_string(), "BCDE".to_string()],
+        ];
+
+        Solver::sort_solutions(&mut solutions, TieBreak::Lexicographic);
+        assert_eq!(solutions[0], vec!["AB".to_string(), "BCDE".to_string()]);
+
+        Solver::sort_solutions(&mut solutions, TieBreak::FewestWordsFirst);
+        assert_eq!(solutions[0], vec!["ABCDEF".to_string()]);
+
+        Solver::sort_solutions(&mut solutions, TieBreak::AlphabeticalFirstWord);
+        assert_eq!(solutions[0], vec!["AB".to_string(), "BCDE".to_string()]);
+        assert_eq!(solutions[2], vec!["CAB".to_string(), "BAD".to_string()]);
+
+        Solver::sort_solutions(&mut solutions, TieBreak::Custom(|a, b| b.last().cmp(&a.last())));
+        assert_eq!(solutions[0], vec!["AB".to_string(), "BCDE".to_string()]);
+    }
+
+    #[test]
+    fn test_solver_state() {
+        let mut state = SolverState::new(&setup_game());
+        assert_eq!(state.frontier().len(), 1);
+
+        assert!(state.step().is_empty());
+        assert_eq!(state.depth(), 1);
+        assert!(state.frontier().iter().all(|game| game.previous_words.len() == 1));
+
+        let solutions = state.step();
+        assert!(solutions.contains(&vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]));
+        assert!(!state.is_exhausted());
+    }
+
+    #[test]
+    fn test_pruning() {
+        let game = Game::new(Sides::from_str("XJZ", "AEI", "OUW", "KVB"));
+        let mut pruned = SolverState::new(&game);
+        let mut unpruned = SolverState::build(&game, &SolverConfig::default(), false);
+
+        for _ in 0..3 {
+            let (mut pruned_solutions, mut unpruned_solutions) = (pruned.step(), unpruned.step());
+            pruned_solutions.sort();
+            unpruned_solutions.sort();
+            assert_eq!(pruned_solutions, unpruned_solutions);
+        }
+
+        assert!(pruned.nodes_expanded() < unpruned.nodes_expanded());
+    }
+
+    #[test]
+    fn test_solve_no_letter_reuse() {
+        let game = Game::new(Sides::from_str("ALM", "UNE", "ICS", "POR"));
+        let solutions = Solver::solve_no_letter_reuse(&game);
+        assert!(!solutions.is_empty());
+
+        for solution in solutions {
+            let mut letters = solution[0].chars().collect::<Vec<char>>();
+            letters.extend(solution[1..].iter().flat_map(|w| w.chars().skip(1)));
+            assert_eq!(letters.len(), 12);
+            assert_eq!(letters.iter().collect::<HashSet<&char>>().len(), 12);
+
+            let mut game = game.clone();
+            assert!(game.apply_words(&solution).is_ok());
+            assert!(game.check_win());
+        }
+    }
+
+    #[test]
+    fn test_solve_k() {
+        let game = setup_game();
+        let best = Solver::solve_k(&game, 3);
+        assert_eq!(best.len(), 3);
+        assert!(best.contains(&vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]));
+        assert!(best.windows(2).all(|pair| pair[0].join("").len() <= pair[1].join("").len()));
+        for solution in &best {
+            let mut game = game.clone();
+            assert!(game.apply_words(solution).is_ok());
+            assert!(game.check_win());
+        }
+
+        assert!(Solver::solve_k(&game, 0).is_empty());
+    }
+
+    #[test]
+    fn test_solve_k_with_config() {
+        let words = ["OKRA", "ADJECTIVAL", "LOKR"];
+        let game = Game::with_dictionary(setup_game().sides().clone(), words.map(String::from).to_vec());
+        let reversed = SolverConfig { tie_break: TieBreak::Custom(|a, b| b.cmp(a)), ..Default::default() };
+
+        assert_eq!(Solver::solve_k(&game, 1), vec![solution(&["ADJECTIVAL", "LOKR"])]);
+        assert_eq!(Solver::solve_k_with_config(&game, 1, &reversed), vec![solution(&["OKRA", "ADJECTIVAL"])]);
+        assert_eq!(Solver::solve_k_with_config(&game, 2, &reversed), vec![
+            solution(&["OKRA", "ADJECTIVAL"]),
+            solution(&["ADJECTIVAL", "LOKR"]),
+        ]);
+    }
+
+    #[test]
+    fn test_letter_frequency_in_solutions() {
+        let frequencies = Solver::letter_frequency_in_solutions(&fixture_game());
+        assert_eq!(frequencies.len(), 12);
+        assert_eq!(frequencies[&'A'], 3);
+        assert_eq!(frequencies[&'K'], 1);
+        assert_eq!(frequencies.values().sum::<usize>(), 14);
+
+        let game = Game::with_dictionary(setup_game().sides().clone(), vec!["OKRA".to_string()]);
+        assert!(Solver::letter_frequency_in_solutions(&game).is_empty());
+    }
+
+    #[test]
+    fn test_length_distribution() {
+        let game = fixture_game();
+        assert_eq!(Solver::length_distribution(&game), BTreeMap::from([(2, 1), (3, 2)]));
+        assert_eq!(Solver::length_distribution_within(&game, 2), BTreeMap::from([(2, 1)]));
+
+        let words = ["OKRA", "KOJI", "AKA"];
+        let game = Game::with_dictionary(setup_game().sides().clone(), words.map(String::from).to_vec());
+        assert!(Solver::length_distribution(&game).is_empty());
+    }
+
+    #[test]
+    fn test_solve_astar() {
+        let game = setup_game();
+        let (best, expanded) = Solver::astar(&game);
+        let best = best.unwrap();
+        assert_eq!(best.join("").len(), 14);
+        let mut played = game.clone();
+        assert!(played.apply_words(&best).is_ok());
+        assert!(played.check_win());
+
+        let mut state = SolverState::new(&game);
+        while state.step().is_empty() {}
+        assert!(expanded < state.nodes_expanded());
+
+        assert_eq!(Solver::solve_astar(&fixture_game()), Some(solution(&["OKRA", "ADJECTIVAL"])));
+    }
+
+    #[test]
+    fn test_is_two_word_solvable() {
+        assert!(Solver::is_two_word_solvable(&setup_game()));
+        assert!(Solver::is_two_word_solvable(&fixture_game()));
+
+        let words = ["JIVED", "DOLT", "TRACK", "KOJI", "VELDT"];
+        let game = Game::with_dictionary(setup_game().sides().clone(), words.map(String::from).to_vec());
+        assert!(!Solver::is_two_word_solvable(&game));
+        assert_eq!(Solver::min_word_count(&game), Some(3));
+    }
+
+    #[test]
+    fn test_solve_optimal() {
+        let game = fixture_game();
+        let optimal = vec![
+            solution(&["JIVED", "DOLT", "TRACK"]),
+            solution(&["OKRA", "ADJECTIVAL"]),
+            solution(&["VELDT", "TRACK", "KOJI"]),
+        ];
+        let (pruned, pruned_expanded) = Solver::optimal_search(&game, true);
+        let (unpruned, unpruned_expanded) = Solver::optimal_search(&game, false);
+        assert_eq!(pruned, optimal);
+        assert_eq!(unpruned, optimal);
+        assert!(pruned_expanded < unpruned_expanded);
+
+        let best = Solver::solve_optimal(&setup_game());
+        assert!(best.contains(&solution(&["OKRA", "ADJECTIVAL"])));
+        assert!(best.iter().all(|s| s.join("").len() == 14));
+    }
+
+    #[test]
+    fn test_min_word_count() {
+        let mut game = setup_game();
+        assert_eq!(Solver::min_word_count(&game), Some(2));
+
+        game.play_word("OKRA");
+        assert_eq!(Solver::min_word_count(&game), Some(2));
+
+        let game = Game::new(Sides::from_shareable("QZX JTA CLV ERO").unwrap());
+        assert_eq!(Solver::min_word_count(&game), None);
+    }
+
+    #[test]
+    fn test_max_solutions() {
+        let game = Game::new(Sides::from_str("ALM", "UNE", "ICS", "POR"));
+        let all = Solver::solve(&game);
+        assert!(all.len() > 5);
+
+        let config = SolverConfig { max_solutions: Some(5), ..Default::default() };
+        let best = Solver::solve_with_config(&game, 0, &config);
+        assert_eq!(best, all[..5]);
+
+        let config = SolverConfig { max_solutions: Some(2), ..Default::default() };
+        assert_eq!(Solver::solve_with_config(&fixture_game(), 3, &config), vec![
+            solution(&["JIVED", "DOLT", "TRACK"]),
+            solution(&["OKRA", "ADJECTIVAL"]),
+        ]);
+    }
+
+    #[test]
+    fn test_min_frequency() {
+        let game = setup_game();
+        let possible_words = game.possible_words();
+        let frequencies = possible_words
+            .iter()
+            .map(|w| (w.clone(), w.len() as f64))
+            .collect::<HashMap<String, f64>>();
+
+        let mut config = SolverConfig { frequencies: Some(frequencies), ..Default::default() };
+        let mut candidates = possible_words.len();
+        for min_frequency in [3.0, 5.0, 8.0, 11.0] {
+            config.min_frequency = Some(min_frequency);
+            let kept = possible_words.iter().filter(|w| config.keeps_word(w)).count();
+            assert!(kept <= candidates);
+            candidates = kept;
+        }
+        assert!(candidates < possible_words.len());
+
+        config.min_frequency = Some(4.0);
+        let solutions = Solver::solve_with_config(&game, 0, &config);
+        assert!(solutions.iter().flatten().all(|w| w.len() >= 4));
+        assert!(solutions.contains(&vec!["OKRA".to_string(), "ADJECTIVAL".to_string()]));
+
+        config.frequencies = None;
+        assert!(!config.keeps_word("OKRA"));
+    }
+
+    #[test]
+    fn test_solve_report() {
+        let mut game = setup_game();
+        game.set_par(Some(5));
+        let report = Solver::solve_report(&game);
+
+        assert_eq!(report.board, ["DKI", "JTA", "CLV", "ERO"].map(String::from));
+        assert_eq!(report.par, Some(5));
+        let best = &report.solutions[0];
+        assert_eq!(best.total_letters, 14);
+        assert_eq!(best.word_count, 2);
+        assert_eq!(best.beats_par, Some(true));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["solutions"][0]["total_letters"], 14);
+
+        let words = |report: &SolveReport| report.solutions.iter().map(|s| s.words.clone()).collect::<Vec<Vec<String>>>();
+        let sorted = Solver::solve_report_sorted(&game, SolutionSort::Lexicographic);
+        assert_eq!(words(&sorted), Solver::solve_sorted(&game, SolutionSort::Lexicographic));
+        assert_ne!(words(&sorted), words(&report));
+
+        let rated = Solver::rate_solutions(&game, vec![solution(&["ADJECTIVE", "EARLOCK"])]);
+        assert_eq!(rated.solutions.len(), 1);
+        assert_eq!(rated.solutions[0].total_letters, 16);
+        assert_eq!(rated.par, Some(5));
+    }
+
+    #[test]
+    fn test_rarest_word() {
+        let solution = vec!["OKRA".to_string(), "ADJECTIVAL".to_string()];
+        assert_eq!(Solver::rarest_word(&solution, None), Some("ADJECTIVAL"));
+
+        let frequencies = HashMap::from([("OKRA".to_string(), 0.1), ("ADJECTIVAL".to_string(), 2.0)]);
+        assert_eq!(Solver::rarest_word(&solution, Some(&frequencies)), Some("OKRA"));
+
+        let frequencies = HashMap::from([("ADJECTIVAL".to_string(), 2.0)]);
+        assert_eq!(Solver::rarest_word(&solution, Some(&frequencies)), Some("OKRA"));
+
+        let report = Solver::solve_report(&setup_game());
+        assert_eq!(report.solutions[0].rarest_word.as_deref(), Some("ADJECTIVAL"));
+    }
+
+    #[test]
+    fn test_solve_deterministic() {
+        let game = setup_game();
+        assert_eq!(Solver::solve(&game), Solver::solve(&game));
     }
 }
@@ -1,42 +1,246 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::process::ExitCode;
 use std::time::Instant;
 use letter_boxed_bot::*;
 
-fn solve(game: &Game) {
+/// Cuts the line down to at most `width` characters, if given.
+fn fit(line: &str, width: Option<usize>) -> String {
+    match width {
+        Some(width) => line.chars().take(width).collect(),
+        None => line.to_string(),
+    }
+}
+
+/// Makes the text bold if colors are on.
+fn bold(text: &str, use_color: bool) -> String {
+    if use_color {
+        format!("\x1b[1m{}\x1b[0m", text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// Checks if output can use ANSI colors: only when printing to a terminal, and `NO_COLOR` isn't set.
+fn use_color() -> bool {
+    io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none()
+}
+
+/// Gets the number of columns of the terminal stdout is printing to, if it can tell.
+#[cfg(unix)]
+fn terminal_columns() -> Option<usize> {
+    // SAFETY: TIOCGWINSZ only writes a `winsize` to the pointer it's given, which is valid for the call.
+    let mut size = unsafe { std::mem::zeroed::<libc::winsize>() };
+    let result = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) };
+    (result == 0 && size.ws_col > 0).then_some(size.ws_col as usize)
+}
+
+#[cfg(not(unix))]
+fn terminal_columns() -> Option<usize> {
+    None
+}
+
+/// Gets the width to fit output to: the `--width` argument if given, otherwise the terminal width when printing
+/// to a terminal, falling back to `COLUMNS` if the terminal can't tell, or no limit.
+fn output_width(args: &[String]) -> Result<Option<usize>, String> {
+    if let Some(i) = args.iter().position(|a| a == "--width") {
+        return args
+            .get(i + 1)
+            .and_then(|w| w.parse().ok())
+            .filter(|&w| w > 0)
+            .map(Some)
+            .ok_or_else(|| "expected a positive number after --width".to_string());
+    }
+
+    if !io::stdout().is_terminal() {
+        return Ok(None);
+    }
+    Ok(terminal_columns().or_else(|| env::var("COLUMNS").ok().and_then(|w| w.parse().ok()).filter(|&w| w > 0)))
+}
+
+fn solve(game: &Game, solver: &dyn Solve, width: Option<usize>, use_color: bool) {
     let start = Instant::now();
 
-    let solutions = Solver::solve(game);
+    let solutions = solver.solve(game);
 
-    let max_first_word_len = solutions
-        .iter()
-        .map(|s| s[0].len())
-        .max()
-        .unwrap();
-    let max_second_word_len = solutions
-        .iter()
-        .map(|s| s[1].len())
-        .max()
-        .unwrap();
+    if solutions.is_empty() {
+        for line in game.sides().to_string().lines() {
+            println!("{}", fit(line, width));
+        }
+        println!("\nNo solutions found in {:?}\n", start.elapsed());
+        return;
+    }
 
+    let widths = column_widths(&solutions);
+    let separator = fit(&"-".repeat(widths.iter().sum::<usize>() + 3 * widths.len().saturating_sub(1)), width);
 
-    println!("{}", "-".repeat(max_first_word_len + max_second_word_len + 3));
+    println!("{}", separator);
 
-    println!("{}", game.format_board());
+    for line in game.sides().to_string().lines() {
+        println!("{}", fit(line, width));
+    }
 
     for s in &solutions {
-        println!("{} {:>max_first_word_len$} | {:<max_second_word_len$}", s.join("").len(), s[0], s[1]);
+        let row = format!("{} {}", s.join("").len(), format_words(s, &widths));
+        println!("{}", fit(&row, width));
     }
 
-    println!("\n{} solutions found in {:?}", solutions.len(), start.elapsed());
-    println!("{}\n", "-".repeat(max_first_word_len + max_second_word_len + 3));
+    let summary = fit(&format!("{} solutions found in {:?}", solutions.len(), start.elapsed()), width);
+    println!("\n{}", bold(&summary, use_color));
+    println!("{}\n", separator);
 }
 
-fn main() {
+/// Gets the length of the longest word in each position across the solutions, which can have any number of words.
+fn column_widths(solutions: &[Vec<String>]) -> Vec<usize> {
+    let mut widths = Vec::new();
+    for solution in solutions {
+        for (i, word) in solution.iter().enumerate() {
+            if i == widths.len() {
+                widths.push(0);
+            }
+            widths[i] = widths[i].max(word.len());
+        }
+    }
+    widths
+}
+
+/// Lines up the words of a solution in columns, with the first word right-aligned and the rest left-aligned.
+fn format_words(solution: &[String], widths: &[usize]) -> String {
+    solution
+        .iter()
+        .zip(widths)
+        .enumerate()
+        .map(|(i, (word, &width))| if i == 0 { format!("{:>width$}", word) } else { format!("{:<width$}", word) })
+        .collect::<Vec<String>>()
+        .join(" | ")
+}
+
+/// Parses a board given either as four sides ("DKI JTA CLV ERO") or as twelve letters read clockwise.
+fn parse_board(board: &str) -> Result<Sides, String> {
+    Sides::from_shareable(board).map_err(|e| e.to_string())
+}
+
+/// The options that are followed by a value.
+const OPTIONS_WITH_VALUES: [&str; 3] = ["--algorithm", "--width", "--board"];
+
+/// Gets the game to solve: the board from `--board` or four side arguments, yesterday's game with `--yesterday`,
+/// or today's game.
+/// Usage: `[--board "DKI JTA CLV ERO" | DKI JTA CLV ERO | --yesterday]`
+fn pick_game(args: &[String]) -> Result<Game, String> {
+    let mut positional = Vec::new();
+    let mut board = None;
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == "--board" {
+            board = Some(args.get(i + 1).ok_or("expected a board after --board")?.clone());
+        }
+        if OPTIONS_WITH_VALUES.contains(&args[i].as_str()) {
+            i += 2;
+            continue;
+        }
+        if !args[i].starts_with("--") {
+            positional.push(args[i].as_str());
+        }
+        i += 1;
+    }
+
+    let yesterday = args.iter().any(|a| a == "--yesterday");
+    let board = match (board, positional.len()) {
+        (None, 0) => None,
+        (None, 4) => Some(positional.join(" ")),
+        (Some(board), 0) => Some(board),
+        _ => return Err("expected a board as --board \"DKI JTA CLV ERO\" or four sides, like DKI JTA CLV ERO".to_string()),
+    };
+
+    match (board, yesterday) {
+        (Some(_), true) => Err("--yesterday can't be used with a board".to_string()),
+        (Some(board), false) => parse_board(&board).map(Game::new).map_err(|e| format!("invalid board: {}", e)),
+        (None, true) => Game::try_yesterday().map_err(|e| format!("couldn't get yesterday's game: {}", e)),
+        (None, false) => Game::try_today().map_err(|e| format!("couldn't get today's game: {}", e)),
+    }
+}
+
+/// Checks whether the given words solve the given board.
+/// Usage: `verify --board "DKI JTA CLV ERO" WORD1 WORD2 ...`
+fn verify(args: &[String]) -> ExitCode {
+    let (Some("--board"), Some(board)) = (args.first().map(String::as_str), args.get(1)) else {
+        eprintln!("usage: verify --board \"TOP RIGHT BOTTOM LEFT\" WORD...");
+        return ExitCode::FAILURE;
+    };
+
+    let sides = match parse_board(board) {
+        Ok(sides) => sides,
+        Err(error) => {
+            eprintln!("invalid board: {}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let words = args[2..].iter().map(|w| w.to_uppercase()).collect::<Vec<String>>();
+    let mut game = Game::new(sides);
+
+    if let Err((i, rejection)) = game.apply_words(&words) {
+        println!("invalid: word {} ({}): {}", i + 1, words[i], rejection);
+        return ExitCode::FAILURE;
+    }
+
+    if !game.check_win() {
+        println!("invalid: not every letter is used");
+        return ExitCode::FAILURE;
+    }
+
+    println!("valid solution ({} words, {} letters)", words.len(), words.join("").len());
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.first().is_some_and(|a| a == "verify") {
+        return verify(&args[1..]);
+    }
+
+    let json = args.iter().any(|a| a == "--json");
+
+    let algorithm = args
+        .iter()
+        .position(|a| a == "--algorithm")
+        .map(|i| args.get(i + 1).map(String::as_str).unwrap_or_default());
+    let solver: &dyn Solve = match algorithm {
+        None | Some("exhaustive") => &ExhaustiveSolver,
+        Some("beam") => &BeamSolver { width: 1000 },
+        Some("greedy") => &GreedySolver,
+        Some(algorithm) => {
+            eprintln!("unknown algorithm {:?}, expected exhaustive, beam, or greedy", algorithm);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let width = match output_width(&args) {
+        Ok(width) => width,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+
     let s = Instant::now();
 
-    if let Some(game) = Game::today() {
-        solve(&game);
-        Solver::solve(&game);
+    let game = match pick_game(&args) {
+        Ok(game) => game,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
     };
 
+    if json {
+        let report = Solver::rate_solutions(&game, solver.solve(&game));
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        return ExitCode::SUCCESS;
+    }
+
+    solve(&game, solver, width, use_color());
+
     eprintln!("Finished in {:?}", s.elapsed());
+    ExitCode::SUCCESS
 }
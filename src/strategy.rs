@@ -0,0 +1,138 @@
+use rayon::prelude::*;
+
+use crate::{Game, Solver, MAX_DEPTH};
+
+/// A way of finding solutions to a game.
+pub trait Solve {
+    /// Finds solutions to the given game, sorted by total character count.
+    fn solve(&self, game: &Game) -> Vec<Vec<String>>;
+}
+
+/// Searches every chain of words, giving all of the shortest solutions. Same as `Solver::solve`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ExhaustiveSolver;
+
+impl Solve for ExhaustiveSolver {
+    fn solve(&self, game: &Game) -> Vec<Vec<String>> {
+        Solver::solve(game)
+    }
+}
+
+/// Searches breadth-first, but only keeps the `width` most promising games at each depth, preferring games
+/// with fewer letters left and then fewer letters played. Much faster than `ExhaustiveSolver`, but may miss
+/// solutions.
+#[derive(Clone, Copy, Debug)]
+pub struct BeamSolver {
+    pub width: usize,
+}
+
+impl Solve for BeamSolver {
+    fn solve(&self, game: &Game) -> Vec<Vec<String>> {
+        if !game.is_possibly_solvable() {
+            return Vec::new();
+        }
+
+        let words_by_letter = game.words_by_first_letter();
+        let mut games = vec![game.clone()];
+
+        for _ in 0..MAX_DEPTH {
+            let next_games = games.par_iter()
+                .flat_map(|game| {
+                    Solver::quick_filter_possible_words(game, &words_by_letter)
+                        .into_iter()
+                        .map(|w| {
+                            let mut game = game.clone();
+                            game.play_word(w);
+                            game
+                        })
+                        .collect::<Vec<Game>>()
+                })
+                .collect::<Vec<Game>>();
+
+            let (won, mut unfinished): (Vec<Game>, Vec<Game>) = next_games.into_iter().partition(Game::check_win);
+            if !won.is_empty() {
+                let mut solutions = won.into_iter().map(|game| game.previous_words).collect::<Vec<Vec<String>>>();
+                Solver::sort_solutions(&mut solutions, Default::default());
+                return solutions;
+            }
+
+            unfinished.sort_by_cached_key(|game| {
+                let joined = game.previous_words.join("");
+                (game.remaining_letters.len(), joined.len(), joined)
+            });
+            unfinished.truncate(self.width);
+            games = unfinished;
+        }
+
+        Vec::new()
+    }
+}
+
+/// Plays the word that covers the most new letters each turn, preferring shorter words and then
+/// alphabetical order. Gives at most one solution, and none if it gets stuck.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GreedySolver;
+
+impl Solve for GreedySolver {
+    fn solve(&self, game: &Game) -> Vec<Vec<String>> {
+        if !game.is_possibly_solvable() {
+            return Vec::new();
+        }
+
+        let words_by_letter = game.words_by_first_letter();
+        let mut game = game.clone();
+
+        for _ in 0..MAX_DEPTH {
+            let best = Solver::quick_filter_possible_words(&game, &words_by_letter)
+                .into_iter()
+                .map(|w| (game.new_letters_if_played(w), w))
+                .filter(|&(new_letters, _)| new_letters > 0)
+                .min_by(|(a_new, a), (b_new, b)| b_new.cmp(a_new).then(a.len().cmp(&b.len())).then(a.cmp(b)));
+
+            let Some((_, word)) = best else {
+                break;
+            };
+
+            game.play_word(word);
+            if game.check_win() {
+                return vec![game.previous_words];
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Sides;
+
+    fn setup_game() -> Game {
+        let sides = Sides::from_str("DKI", "JTA", "CLV", "ERO");
+        Game::new(sides)
+    }
+
+    #[test]
+    fn test_strategies() {
+        let game = setup_game();
+        let strategies: [&dyn Solve; 3] = [&ExhaustiveSolver, &BeamSolver { width: 100 }, &GreedySolver];
+
+        for strategy in strategies {
+            let solutions = strategy.solve(&game);
+            assert!(!solutions.is_empty());
+            for solution in solutions {
+                let mut game = setup_game();
+                assert!(game.apply_words(&solution).is_ok());
+                assert!(game.check_win());
+            }
+        }
+
+        assert_eq!(ExhaustiveSolver.solve(&game), Solver::solve(&game));
+
+        let game = Game::new(Sides::from_str("QZX", "JTA", "CLV", "ERO"));
+        for strategy in strategies {
+            assert!(strategy.solve(&game).is_empty());
+        }
+    }
+}